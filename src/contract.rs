@@ -1,10 +1,14 @@
+use crate::error::ContractError;
 use crate::error::ContractResult;
-use crate::msg::{ClientMsg, CreditMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, PoolMsg, QueryMsg};
+use crate::msg::{
+  ClientMsg, CreditMsg, ExecuteMsg, GovernanceMsg, HookMsg, InitiatorMsg, InstantiateMsg,
+  JackpotMsg, MigrateMsg, PlayerMsg, PoolMsg, QueryMsg, ReferralMsg, TimelockMsg,
+};
 use crate::query;
-use crate::state::{self};
-use crate::{execute, migrations};
+use crate::state::{self, HOOK_REPLY_ID};
+use crate::{execute, migrations, reply};
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError};
 use cw2::set_contract_version;
 
 const CONTRACT_NAME: &str = "crates.io:sath";
@@ -31,8 +35,12 @@ pub fn execute(
 ) -> ContractResult<Response> {
   match msg {
     ExecuteMsg::SetConfig { config } => execute::set_config(deps, env, info, config),
-    ExecuteMsg::SetOwner { owner } => execute::set_owner(deps, env, info, owner),
+    ExecuteMsg::ProposeOwner { owner } => execute::propose_owner(deps, env, info, owner),
+    ExecuteMsg::AcceptOwnership => execute::accept_ownership(deps, env, info),
+    ExecuteMsg::CancelOwnershipTransfer => execute::cancel_ownership_transfer(deps, env, info),
     ExecuteMsg::PayTaxes => execute::pay_taxes(deps, env, info),
+    ExecuteMsg::Pause { scopes } => execute::pause(deps, env, info, scopes),
+    ExecuteMsg::Unpause { scopes } => execute::unpause(deps, env, info, scopes),
     ExecuteMsg::SetTaxes { recipients } => execute::set_taxes(deps, env, info, recipients),
     ExecuteMsg::Receive { revenue } => execute::receive(deps, env, info, revenue),
     ExecuteMsg::ProcessMany(jobs) => execute::process_many(deps, env, info, jobs),
@@ -40,11 +48,31 @@ pub fn execute(
       initiator,
       incoming,
       outgoing,
-    } => execute::process_one(deps, env, info, initiator, incoming, outgoing),
+      jackpot,
+    } => execute::process_one(deps, env, info, initiator, incoming, outgoing, jackpot),
 
     ExecuteMsg::Pool(msg) => match msg {
-      PoolMsg::Stake { amount } => execute::pool::stake(deps, env, info, amount),
+      PoolMsg::Stake {
+        amount,
+        lockup_seconds,
+        tranche,
+      } => execute::pool::stake(deps, env, info, amount, lockup_seconds, tranche),
+      PoolMsg::StakeFor {
+        beneficiary,
+        amount,
+      } => execute::pool::stake_for(deps, env, info, beneficiary, amount),
+      PoolMsg::SetRewardRecipient { recipient } => {
+        execute::pool::set_reward_recipient(deps, env, info, recipient)
+      },
+      PoolMsg::FundRewards {
+        token,
+        amount,
+        start,
+        end,
+      } => execute::pool::fund_rewards(deps, env, info, token, amount, start, end),
+      PoolMsg::ClaimRewards { token } => execute::pool::claim_rewards(deps, env, info, token),
       PoolMsg::Unstake => execute::pool::unstake(deps, env, info),
+      PoolMsg::Rebond => execute::pool::rebond(deps, env, info),
       PoolMsg::Withdraw => execute::pool::withdraw(deps, env, info),
       PoolMsg::Claim => execute::pool::claim(deps, env, info),
     },
@@ -54,6 +82,9 @@ pub fn execute(
       ClientMsg::Disconnect { address } => execute::client::disconnect(deps, env, info, address),
       ClientMsg::Suspend { address } => execute::client::suspend(deps, env, info, address),
       ClientMsg::Resume { address } => execute::client::resume(deps, env, info, address),
+      ClientMsg::ClaimRevenueShare { address } => {
+        execute::client::claim_revenue_share(deps, env, info, address)
+      },
       ClientMsg::SetConfig { address, config } => {
         execute::client::set_client_config(deps, env, info, address, config)
       },
@@ -63,6 +94,100 @@ pub fn execute(
       CreditMsg::Deposit { amount } => execute::credit::deposit(deps, env, info, amount),
       CreditMsg::Withdraw { amount } => execute::credit::withdraw(deps, env, info, amount),
     },
+
+    ExecuteMsg::Player(msg) => match msg {
+      PlayerMsg::SetLimits { limits } => execute::player::set_limits(deps, env, info, limits),
+      PlayerMsg::CancelPendingLimits => execute::player::cancel_pending_limits(deps, env, info),
+      PlayerMsg::ClaimRakeback { to_bank_account } => {
+        execute::player::claim_rakeback(deps, env, info, to_bank_account)
+      },
+    },
+
+    ExecuteMsg::Initiators(msg) => match msg {
+      InitiatorMsg::Deny {
+        address,
+        reason,
+        expires_at,
+      } => execute::initiators::deny(deps, env, info, address, reason, expires_at),
+      InitiatorMsg::Undeny { address } => execute::initiators::undeny(deps, env, info, address),
+      InitiatorMsg::Allow {
+        client,
+        address,
+        reason,
+        expires_at,
+      } => execute::initiators::allow(deps, env, info, client, address, reason, expires_at),
+      InitiatorMsg::Disallow { client, address } => {
+        execute::initiators::disallow(deps, env, info, client, address)
+      },
+    },
+
+    ExecuteMsg::Referrals(msg) => match msg {
+      ReferralMsg::SetReferrer { referrer } => {
+        execute::referrals::set_referrer(deps, env, info, referrer)
+      },
+      ReferralMsg::Claim => execute::referrals::claim(deps, env, info),
+    },
+
+    ExecuteMsg::Jackpots(msg) => match msg {
+      JackpotMsg::Create {
+        name,
+        client,
+        contribution_pct,
+      } => execute::jackpots::create(deps, env, info, name, client, contribution_pct),
+      JackpotMsg::SetContributionPct {
+        name,
+        contribution_pct,
+      } => execute::jackpots::set_contribution_pct(deps, env, info, name, contribution_pct),
+      JackpotMsg::Remove { name } => execute::jackpots::remove(deps, env, info, name),
+    },
+
+    ExecuteMsg::Governance(msg) => match msg {
+      GovernanceMsg::Propose {
+        title,
+        description,
+        actions,
+      } => execute::governance::propose(deps, env, info, title, description, actions),
+      GovernanceMsg::Vote { id, vote } => execute::governance::vote(deps, env, info, id, vote),
+      GovernanceMsg::Execute { id } => execute::governance::execute(deps, env, info, id),
+    },
+
+    ExecuteMsg::Hooks(msg) => match msg {
+      HookMsg::Add {
+        address,
+        triggers,
+        loss_threshold,
+        gas_limit,
+      } => execute::hooks::add(
+        deps,
+        env,
+        info,
+        address,
+        triggers,
+        loss_threshold,
+        gas_limit,
+      ),
+      HookMsg::Remove { address } => execute::hooks::remove(deps, env, info, address),
+    },
+
+    ExecuteMsg::Timelock(msg) => match msg {
+      TimelockMsg::Execute { id } => execute::timelock::execute(deps, env, info, id),
+      TimelockMsg::Cancel { id } => execute::timelock::cancel(deps, env, info, id),
+    },
+  }
+}
+
+#[entry_point]
+pub fn reply(
+  deps: DepsMut,
+  env: Env,
+  msg: Reply,
+) -> ContractResult<Response> {
+  match msg.id {
+    HOOK_REPLY_ID => reply::hook(deps, env, msg),
+    _ => Err(ContractError::Std(StdError::generic_err(format!(
+      "unknown reply id: {}",
+      msg.id
+    )))),
   }
 }
 
@@ -81,19 +206,57 @@ pub fn query(
       initiator,
       amount,
     } => to_binary(&query::can_spend(deps, env, client, initiator, amount)?),
+    QueryMsg::Events {
+      start_after,
+      limit,
+      client,
+      kind,
+    } => to_binary(&query::events(deps, start_after, limit, client, kind)?),
+    QueryMsg::ClientStats { address, from, to } => {
+      to_binary(&query::client_stats(deps, address, from, to)?)
+    },
+    QueryMsg::Player { address } => to_binary(&query::player(deps, env, address)?),
+    QueryMsg::Referrals {
+      referrer,
+      cursor,
+      limit,
+    } => to_binary(&query::referrals(deps, referrer, cursor, limit)?),
+    QueryMsg::Players {
+      order_by,
+      cursor,
+      limit,
+    } => to_binary(&query::players(deps, order_by, cursor, limit)?),
+    QueryMsg::Proposal { id } => to_binary(&query::proposal(deps, env, id)?),
+    QueryMsg::Proposals { cursor, limit } => {
+      to_binary(&query::proposals(deps, env, cursor, limit)?)
+    },
+    QueryMsg::VotingPower { address, height } => {
+      to_binary(&query::voting_power(deps, env, address, height)?)
+    },
+    QueryMsg::StakedBalanceAtHeight { address, height } => to_binary(
+      &query::staked_balance_at_height(deps, env, address, height)?,
+    ),
+    QueryMsg::TotalStakedAtHeight { height } => {
+      to_binary(&query::total_staked_at_height(deps, env, height)?)
+    },
+    QueryMsg::StakeAt { address, height } => {
+      to_binary(&query::stake_at(deps, env, address, height)?)
+    },
+    QueryMsg::TotalStakeAt { height } => to_binary(&query::total_stake_at(deps, env, height)?),
   }?)
 }
 
 #[entry_point]
 pub fn migrate(
   deps: DepsMut,
-  _env: Env,
+  env: Env,
   msg: MigrateMsg,
 ) -> ContractResult<Response> {
   set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
   match msg {
     MigrateMsg::V0_0_4 {} => migrations::v0_0_4::migrate(deps),
     MigrateMsg::V0_0_5 {} => migrations::v0_0_5::migrate(deps),
+    MigrateMsg::V0_0_6 {} => migrations::v0_0_6::migrate(deps, env),
     MigrateMsg::NoOp {} => Ok(Response::default()),
   }
 }
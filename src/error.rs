@@ -47,6 +47,195 @@ pub enum ContractError {
 
   #[error("BudgetExceeded")]
   BudgetExceeded,
+
+  #[error("ClientAlreadyConnected")]
+  ClientAlreadyConnected,
+
+  #[error("MissingClientAddress")]
+  MissingClientAddress,
+
+  #[error("InvalidRestakeRate")]
+  InvalidRestakeRate,
+
+  #[error("InvalidTaxRate")]
+  InvalidTaxRate,
+
+  #[error("InvalidIntervalSeconds")]
+  InvalidIntervalSeconds,
+
+  #[error("InvalidMaxPctChange")]
+  InvalidMaxPctChange,
+
+  #[error("InvalidTaxRecipientPct")]
+  InvalidTaxRecipientPct,
+
+  #[error("InvalidTaxRecipientTotal")]
+  InvalidTaxRecipientTotal,
+
+  #[error("MissingTaxRecipientAddress")]
+  MissingTaxRecipientAddress,
+
+  #[error("DuplicateTaxRecipient")]
+  DuplicateTaxRecipient,
+
+  #[error("InvalidClientBudget")]
+  InvalidClientBudget,
+
+  #[error("PendingChangeNotFound")]
+  PendingChangeNotFound,
+
+  #[error("TimelockNotExpired")]
+  TimelockNotExpired,
+
+  #[error("InvalidTimelockSeconds")]
+  InvalidTimelockSeconds,
+
+  #[error("NoPendingOwner")]
+  NoPendingOwner,
+
+  #[error("Paused")]
+  Paused,
+
+  #[error("TooManyHooks")]
+  TooManyHooks,
+
+  #[error("HookNotFound")]
+  HookNotFound,
+
+  #[error("InvalidStatsBucketSeconds")]
+  InvalidStatsBucketSeconds,
+
+  #[error("InvalidMaxEventLogSize")]
+  InvalidMaxEventLogSize,
+
+  #[error("InvalidPlayerLimits")]
+  InvalidPlayerLimits,
+
+  #[error("NoPendingLimits")]
+  NoPendingLimits,
+
+  #[error("PlayerLimitReached")]
+  PlayerLimitReached,
+
+  #[error("InitiatorDenied")]
+  InitiatorDenied,
+
+  #[error("InitiatorNotAllowed")]
+  InitiatorNotAllowed,
+
+  #[error("InitiatorNotListed")]
+  InitiatorNotListed,
+
+  #[error("InvalidRakebackRate")]
+  InvalidRakebackRate,
+
+  #[error("NothingToClaim")]
+  NothingToClaim,
+
+  #[error("InvalidReferralRate")]
+  InvalidReferralRate,
+
+  #[error("InvalidReferrer")]
+  InvalidReferrer,
+
+  #[error("ReferrerNotApproved")]
+  ReferrerNotApproved,
+
+  #[error("ReferrerAlreadySet")]
+  ReferrerAlreadySet,
+
+  #[error("InvalidRevenueSharePct")]
+  InvalidRevenueSharePct,
+
+  #[error("InvalidJackpotContributionPct")]
+  InvalidJackpotContributionPct,
+
+  #[error("JackpotNotFound")]
+  JackpotNotFound,
+
+  #[error("JackpotAlreadyExists")]
+  JackpotAlreadyExists,
+
+  #[error("JackpotNotEmpty")]
+  JackpotNotEmpty,
+
+  #[error("InsufficientJackpotBalance")]
+  InsufficientJackpotBalance,
+
+  #[error("TooManyJackpots")]
+  TooManyJackpots,
+
+  #[error("JackpotClientMismatch")]
+  JackpotClientMismatch,
+
+  #[error("JackpotPayoutExceedsContributions")]
+  JackpotPayoutExceedsContributions,
+
+  #[error("JackpotWithoutPayout")]
+  JackpotWithoutPayout,
+
+  #[error("InvalidLockupTier")]
+  InvalidLockupTier,
+
+  #[error("LockupNotExpired")]
+  LockupNotExpired,
+
+  #[error("InvalidJuniorRevenueMultiplier")]
+  InvalidJuniorRevenueMultiplier,
+
+  #[error("TrancheMismatch")]
+  TrancheMismatch,
+
+  #[error("InvalidRewardStream")]
+  InvalidRewardStream,
+
+  #[error("RewardTokenNotFound")]
+  RewardTokenNotFound,
+
+  #[error("TooManyRewardTokens")]
+  TooManyRewardTokens,
+
+  #[error("TooManyRewardStreams")]
+  TooManyRewardStreams,
+
+  #[error("InvalidStakeLimits")]
+  InvalidStakeLimits,
+
+  #[error("StakeBelowMinimum")]
+  StakeBelowMinimum,
+
+  #[error("StakeAboveMaximum")]
+  StakeAboveMaximum,
+
+  #[error("PoolCapacityExceeded")]
+  PoolCapacityExceeded,
+
+  #[error("StakerNotAllowed")]
+  StakerNotAllowed,
+
+  #[error("InvalidGovernanceConfig")]
+  InvalidGovernanceConfig,
+
+  #[error("GovernanceDisabled")]
+  GovernanceDisabled,
+
+  #[error("InvalidProposal")]
+  InvalidProposal,
+
+  #[error("ProposalNotFound")]
+  ProposalNotFound,
+
+  #[error("ProposalNotOpen")]
+  ProposalNotOpen,
+
+  #[error("ProposalNotPassed")]
+  ProposalNotPassed,
+
+  #[error("AlreadyVoted")]
+  AlreadyVoted,
+
+  #[error("InsufficientVotingPower")]
+  InsufficientVotingPower,
 }
 
 pub type ContractResult<T> = Result<T, ContractError>;
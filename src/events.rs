@@ -0,0 +1,608 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Event, Timestamp, Uint128, Uint64};
+
+use crate::{models::Vote, utils::opt_to_string};
+
+/// Typed wasm events emitted by every execute handler that changes state. Each
+/// event type maps to a fixed set of attribute keys, so that indexers can
+/// rebuild pool and account history from events alone. The chain prefixes each
+/// event type with "wasm-", e.g. "wasm-house_stake".
+pub trait ToEvent {
+  fn to_event(&self) -> Event;
+}
+
+#[cw_serde]
+pub struct StakeEvent {
+  pub staker: Addr,
+  pub amount: Uint128,
+  pub delegation: Uint128,
+  pub liquidity: Uint128,
+  pub pool_delegation: Uint128,
+  pub pool_liquidity: Uint128,
+}
+
+#[cw_serde]
+pub struct UnstakeEvent {
+  pub staker: Addr,
+  pub amount: Uint128,
+  pub delegation: Uint128,
+  pub liquidity: Uint128,
+  pub dividends: Uint128,
+  pub unbonding_time: Uint64,
+}
+
+#[cw_serde]
+pub struct RebondEvent {
+  pub staker: Addr,
+  pub amount: Uint128,
+  pub delegation: Uint128,
+  pub liquidity: Uint128,
+  pub pool_delegation: Uint128,
+  pub pool_liquidity: Uint128,
+}
+
+#[cw_serde]
+pub struct WithdrawEvent {
+  pub staker: Addr,
+  pub recipient: Addr,
+  pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct ClaimEvent {
+  pub staker: Addr,
+  pub recipient: Addr,
+  pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum GovernanceEventKind {
+  ProposalCreated,
+  Voted,
+  ProposalExecuted,
+}
+
+#[cw_serde]
+pub struct GovernanceEvent {
+  pub kind: GovernanceEventKind,
+  pub proposal_id: Uint64,
+  pub sender: Addr,
+  pub vote: Option<Vote>,
+  pub power: Uint128,
+}
+
+#[cw_serde]
+pub struct RewardStreamEvent {
+  pub id: u64,
+  pub funder: Addr,
+  pub token: String,
+  pub amount: Uint128,
+  pub start: Timestamp,
+  pub end: Timestamp,
+}
+
+#[cw_serde]
+pub struct RewardRecipientEvent {
+  pub staker: Addr,
+  pub recipient: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct RewardClaimEvent {
+  pub staker: Addr,
+  pub recipient: Addr,
+  pub token: String,
+  pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct ProcessEvent {
+  pub client: Addr,
+  pub initiator: Addr,
+  pub incoming: Uint128,
+  pub outgoing: Uint128,
+  pub revenue: Uint128,
+  pub tax: Uint128,
+  pub rakeback: Uint128,
+  pub referral: Uint128,
+  pub jackpot_contribution: Uint128,
+  pub revenue_share: Uint128,
+  pub delta_revenue: Uint128,
+  pub delta_dividends: Uint128,
+  pub loss: Uint128,
+  pub rate_limited: bool,
+}
+
+#[cw_serde]
+pub struct ReceiveEvent {
+  pub source: Addr,
+  pub revenue: Uint128,
+  pub tax: Uint128,
+  pub delta_revenue: Uint128,
+  pub delta_dividends: Uint128,
+}
+
+#[cw_serde]
+pub struct RateLimitTriggeredEvent {
+  pub client: Addr,
+  pub initiator: Addr,
+  pub target: Addr,
+}
+
+#[cw_serde]
+pub enum ClientEventKind {
+  Connected,
+  Disconnected,
+  Suspended,
+  Resumed,
+  ConfigUpdated,
+}
+
+#[cw_serde]
+pub struct ClientEvent {
+  pub kind: ClientEventKind,
+  pub client: Addr,
+  pub reason: Option<String>,
+}
+
+#[cw_serde]
+pub struct ClientRevenueShareClaimEvent {
+  pub client: Addr,
+  pub recipient: Addr,
+  pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum JackpotEventKind {
+  Created,
+  Updated,
+  Removed,
+  PaidOut,
+}
+
+#[cw_serde]
+pub struct JackpotEvent {
+  pub kind: JackpotEventKind,
+  pub name: String,
+  pub amount: Uint128,
+  pub recipient: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct TaxPaymentEvent {
+  pub recipient: Addr,
+  pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum CreditEventKind {
+  Deposit,
+  Withdraw,
+}
+
+#[cw_serde]
+pub struct CreditEvent {
+  pub kind: CreditEventKind,
+  pub account: Addr,
+  pub amount: Uint128,
+  pub balance: Uint128,
+}
+
+#[cw_serde]
+pub enum InitiatorListEventKind {
+  Denied,
+  Undenied,
+  Allowed,
+  Disallowed,
+}
+
+#[cw_serde]
+pub struct InitiatorListEvent {
+  pub kind: InitiatorListEventKind,
+  pub initiator: Addr,
+  pub client: Option<Addr>,
+  pub reason: Option<String>,
+  pub expires_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct RakebackClaimEvent {
+  pub player: Addr,
+  pub amount: Uint128,
+  pub to_bank_account: bool,
+}
+
+#[cw_serde]
+pub enum ReferralEventKind {
+  ReferrerSet,
+  EarningsClaimed,
+}
+
+#[cw_serde]
+pub struct ReferralEvent {
+  pub kind: ReferralEventKind,
+  pub referrer: Addr,
+  pub player: Option<Addr>,
+  pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum PlayerEventKind {
+  LimitsUpdated,
+  LimitsQueued,
+  PendingLimitsCancelled,
+}
+
+#[cw_serde]
+pub struct PlayerEvent {
+  pub kind: PlayerEventKind,
+  pub player: Addr,
+}
+
+#[cw_serde]
+pub enum AdminEventKind {
+  ConfigUpdated,
+  TaxesUpdated,
+  OwnerProposed,
+  OwnershipAccepted,
+  OwnershipTransferCancelled,
+  Paused,
+  Unpaused,
+  ChangeQueued,
+  ChangeExecuted,
+  ChangeCancelled,
+}
+
+#[cw_serde]
+pub struct AdminEvent {
+  pub kind: AdminEventKind,
+  pub sender: Addr,
+  pub pending_change_id: Option<Uint64>,
+}
+
+impl ToEvent for StakeEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_stake").add_attributes(vec![
+      ("staker", self.staker.to_string()),
+      ("amount", self.amount.to_string()),
+      ("delegation", self.delegation.to_string()),
+      ("liquidity", self.liquidity.to_string()),
+      ("pool_delegation", self.pool_delegation.to_string()),
+      ("pool_liquidity", self.pool_liquidity.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for UnstakeEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_unstake").add_attributes(vec![
+      ("staker", self.staker.to_string()),
+      ("amount", self.amount.to_string()),
+      ("delegation", self.delegation.to_string()),
+      ("liquidity", self.liquidity.to_string()),
+      ("dividends", self.dividends.to_string()),
+      ("unbonding_time", self.unbonding_time.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for RebondEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_rebond").add_attributes(vec![
+      ("staker", self.staker.to_string()),
+      ("amount", self.amount.to_string()),
+      ("delegation", self.delegation.to_string()),
+      ("liquidity", self.liquidity.to_string()),
+      ("pool_delegation", self.pool_delegation.to_string()),
+      ("pool_liquidity", self.pool_liquidity.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for WithdrawEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_withdraw").add_attributes(vec![
+      ("staker", self.staker.to_string()),
+      ("recipient", self.recipient.to_string()),
+      ("amount", self.amount.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for ClaimEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_claim").add_attributes(vec![
+      ("staker", self.staker.to_string()),
+      ("recipient", self.recipient.to_string()),
+      ("amount", self.amount.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for GovernanceEvent {
+  fn to_event(&self) -> Event {
+    let event_type = match self.kind {
+      GovernanceEventKind::ProposalCreated => "house_proposal_created",
+      GovernanceEventKind::Voted => "house_proposal_voted",
+      GovernanceEventKind::ProposalExecuted => "house_proposal_executed",
+    };
+    Event::new(event_type).add_attributes(vec![
+      ("proposal_id", self.proposal_id.to_string()),
+      ("sender", self.sender.to_string()),
+      (
+        "vote",
+        self
+          .vote
+          .as_ref()
+          .map(|v| v.as_str().to_owned())
+          .unwrap_or_default(),
+      ),
+      ("power", self.power.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for RewardStreamEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_reward_stream_funded").add_attributes(vec![
+      ("id", self.id.to_string()),
+      ("funder", self.funder.to_string()),
+      ("token", self.token.clone()),
+      ("amount", self.amount.to_string()),
+      ("start", self.start.seconds().to_string()),
+      ("end", self.end.seconds().to_string()),
+    ])
+  }
+}
+
+impl ToEvent for RewardRecipientEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_reward_recipient_set").add_attributes(vec![
+      ("staker", self.staker.to_string()),
+      ("recipient", opt_to_string(&self.recipient)),
+    ])
+  }
+}
+
+impl ToEvent for RewardClaimEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_reward_claim").add_attributes(vec![
+      ("staker", self.staker.to_string()),
+      ("recipient", self.recipient.to_string()),
+      ("token", self.token.clone()),
+      ("amount", self.amount.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for ProcessEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_process").add_attributes(vec![
+      ("client", self.client.to_string()),
+      ("initiator", self.initiator.to_string()),
+      ("incoming", self.incoming.to_string()),
+      ("outgoing", self.outgoing.to_string()),
+      ("revenue", self.revenue.to_string()),
+      ("tax", self.tax.to_string()),
+      ("rakeback", self.rakeback.to_string()),
+      ("referral", self.referral.to_string()),
+      (
+        "jackpot_contribution",
+        self.jackpot_contribution.to_string(),
+      ),
+      ("revenue_share", self.revenue_share.to_string()),
+      ("delta_revenue", self.delta_revenue.to_string()),
+      ("delta_dividends", self.delta_dividends.to_string()),
+      ("loss", self.loss.to_string()),
+      ("rate_limited", self.rate_limited.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for ReceiveEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_receive").add_attributes(vec![
+      ("source", self.source.to_string()),
+      ("revenue", self.revenue.to_string()),
+      ("tax", self.tax.to_string()),
+      ("delta_revenue", self.delta_revenue.to_string()),
+      ("delta_dividends", self.delta_dividends.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for RateLimitTriggeredEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_rate_limit_triggered").add_attributes(vec![
+      ("client", self.client.to_string()),
+      ("initiator", self.initiator.to_string()),
+      ("target", self.target.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for ClientEvent {
+  fn to_event(&self) -> Event {
+    let event_type = match self.kind {
+      ClientEventKind::Connected => "house_client_connected",
+      ClientEventKind::Disconnected => "house_client_disconnected",
+      ClientEventKind::Suspended => "house_client_suspended",
+      ClientEventKind::Resumed => "house_client_resumed",
+      ClientEventKind::ConfigUpdated => "house_client_config_updated",
+    };
+    Event::new(event_type).add_attributes(vec![
+      ("client", self.client.to_string()),
+      ("reason", self.reason.clone().unwrap_or_default()),
+    ])
+  }
+}
+
+impl ToEvent for ClientRevenueShareClaimEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_client_revenue_share_claim").add_attributes(vec![
+      ("client", self.client.to_string()),
+      ("recipient", self.recipient.to_string()),
+      ("amount", self.amount.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for JackpotEvent {
+  fn to_event(&self) -> Event {
+    let event_type = match self.kind {
+      JackpotEventKind::Created => "house_jackpot_created",
+      JackpotEventKind::Updated => "house_jackpot_updated",
+      JackpotEventKind::Removed => "house_jackpot_removed",
+      JackpotEventKind::PaidOut => "house_jackpot_paid_out",
+    };
+    Event::new(event_type).add_attributes(vec![
+      ("name", self.name.clone()),
+      ("amount", self.amount.to_string()),
+      ("recipient", opt_to_string(&self.recipient)),
+    ])
+  }
+}
+
+impl ToEvent for TaxPaymentEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_tax_payment").add_attributes(vec![
+      ("recipient", self.recipient.to_string()),
+      ("amount", self.amount.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for CreditEvent {
+  fn to_event(&self) -> Event {
+    let event_type = match self.kind {
+      CreditEventKind::Deposit => "house_credit_deposit",
+      CreditEventKind::Withdraw => "house_credit_withdraw",
+    };
+    Event::new(event_type).add_attributes(vec![
+      ("account", self.account.to_string()),
+      ("amount", self.amount.to_string()),
+      ("balance", self.balance.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for InitiatorListEvent {
+  fn to_event(&self) -> Event {
+    let event_type = match self.kind {
+      InitiatorListEventKind::Denied => "house_initiator_denied",
+      InitiatorListEventKind::Undenied => "house_initiator_undenied",
+      InitiatorListEventKind::Allowed => "house_initiator_allowed",
+      InitiatorListEventKind::Disallowed => "house_initiator_disallowed",
+    };
+    Event::new(event_type).add_attributes(vec![
+      ("initiator", self.initiator.to_string()),
+      ("client", opt_to_string(&self.client)),
+      ("reason", self.reason.clone().unwrap_or_default()),
+      (
+        "expires_at",
+        opt_to_string(&self.expires_at.map(|t| t.seconds())),
+      ),
+    ])
+  }
+}
+
+impl ToEvent for RakebackClaimEvent {
+  fn to_event(&self) -> Event {
+    Event::new("house_rakeback_claim").add_attributes(vec![
+      ("player", self.player.to_string()),
+      ("amount", self.amount.to_string()),
+      ("to_bank_account", self.to_bank_account.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for ReferralEvent {
+  fn to_event(&self) -> Event {
+    let event_type = match self.kind {
+      ReferralEventKind::ReferrerSet => "house_referrer_set",
+      ReferralEventKind::EarningsClaimed => "house_referral_earnings_claimed",
+    };
+    Event::new(event_type).add_attributes(vec![
+      ("referrer", self.referrer.to_string()),
+      ("player", opt_to_string(&self.player)),
+      ("amount", self.amount.to_string()),
+    ])
+  }
+}
+
+impl ToEvent for PlayerEvent {
+  fn to_event(&self) -> Event {
+    let event_type = match self.kind {
+      PlayerEventKind::LimitsUpdated => "house_player_limits_updated",
+      PlayerEventKind::LimitsQueued => "house_player_limits_queued",
+      PlayerEventKind::PendingLimitsCancelled => "house_player_pending_limits_cancelled",
+    };
+    Event::new(event_type).add_attributes(vec![("player", self.player.to_string())])
+  }
+}
+
+impl ToEvent for AdminEvent {
+  fn to_event(&self) -> Event {
+    let event_type = match self.kind {
+      AdminEventKind::ConfigUpdated => "house_config_updated",
+      AdminEventKind::TaxesUpdated => "house_taxes_updated",
+      AdminEventKind::OwnerProposed => "house_owner_proposed",
+      AdminEventKind::OwnershipAccepted => "house_ownership_accepted",
+      AdminEventKind::OwnershipTransferCancelled => "house_ownership_transfer_cancelled",
+      AdminEventKind::Paused => "house_paused",
+      AdminEventKind::Unpaused => "house_unpaused",
+      AdminEventKind::ChangeQueued => "house_change_queued",
+      AdminEventKind::ChangeExecuted => "house_change_executed",
+      AdminEventKind::ChangeCancelled => "house_change_cancelled",
+    };
+    Event::new(event_type).add_attributes(vec![
+      ("sender", self.sender.to_string()),
+      (
+        "pending_change_id",
+        self
+          .pending_change_id
+          .map(|id| id.to_string())
+          .unwrap_or_default(),
+      ),
+    ])
+  }
+}
+
+impl AdminEvent {
+  pub fn new(
+    kind: AdminEventKind,
+    sender: &Addr,
+  ) -> Self {
+    Self {
+      kind,
+      sender: sender.clone(),
+      pending_change_id: None,
+    }
+  }
+}
+
+impl ClientEvent {
+  pub fn new(
+    kind: ClientEventKind,
+    client: &Addr,
+    reason: Option<&str>,
+  ) -> Self {
+    Self {
+      kind,
+      client: client.clone(),
+      reason: reason.map(|r| r.to_owned()),
+    }
+  }
+}
+
+impl PlayerEvent {
+  pub fn new(
+    kind: PlayerEventKind,
+    player: &Addr,
+  ) -> Self {
+    Self {
+      kind,
+      player: player.clone(),
+    }
+  }
+}
@@ -0,0 +1,40 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  state::{OWNER, PENDING_OWNER},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+use cw_acl::client::Acl;
+use cw_lib::models::Owner;
+
+/// Complete a transfer of ownership. The sender must be the pending owner or,
+/// if the pending owner is an ACL, be allowed by it to accept ownership.
+pub fn accept_ownership(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+) -> ContractResult<Response> {
+  let action = "accept_ownership";
+  let pending_owner = PENDING_OWNER
+    .may_load(deps.storage)?
+    .ok_or(ContractError::NoPendingOwner)?;
+
+  if !match &pending_owner {
+    Owner::Address(addr) => info.sender == *addr,
+    Owner::Acl(acl_addr) => {
+      let acl = Acl::new(acl_addr);
+      acl.is_allowed(&deps.querier, &info.sender, "/house/accept-ownership")?
+    },
+  } {
+    return Err(ContractError::NotAuthorized {});
+  }
+
+  OWNER.save(deps.storage, &pending_owner)?;
+  PENDING_OWNER.remove(deps.storage);
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action)])
+      .add_event(AdminEvent::new(AdminEventKind::OwnershipAccepted, &info.sender).to_event()),
+  )
+}
@@ -0,0 +1,30 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  state::{ensure_sender_is_allowed, PENDING_OWNER},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+pub fn cancel_ownership_transfer(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+) -> ContractResult<Response> {
+  let action = "cancel_ownership_transfer";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/set-owner")?;
+
+  if !PENDING_OWNER.exists(deps.storage) {
+    return Err(ContractError::NoPendingOwner);
+  }
+
+  PENDING_OWNER.remove(deps.storage);
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action)])
+      .add_event(
+        AdminEvent::new(AdminEventKind::OwnershipTransferCancelled, &info.sender).to_event(),
+      ),
+  )
+}
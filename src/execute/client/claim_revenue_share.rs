@@ -0,0 +1,63 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{ClientRevenueShareClaimEvent, ToEvent},
+  models::PauseScope,
+  state::{ensure_not_paused, load_client, CLIENTS, POOL},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_lib::utils::funds::build_send_submsg;
+
+/// Send a client's accrued revenue share to its payout address, defaulting to
+/// the client contract itself. Only the client or its payout address can claim.
+pub fn claim_revenue_share(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  client_address: Addr,
+) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Claims)?;
+
+  let action = "claim_revenue_share";
+
+  let mut client = load_client(deps.storage, &client_address)?;
+  let recipient = client
+    .config
+    .payout_address
+    .clone()
+    .unwrap_or(client_address.clone());
+
+  if info.sender != client_address && info.sender != recipient {
+    return Err(ContractError::NotAuthorized {});
+  }
+
+  let amount = client.revenue_share_balance.unwrap_or_default();
+
+  if amount.is_zero() {
+    return Err(ContractError::NothingToClaim);
+  }
+
+  client.revenue_share_balance = Some(Uint128::zero());
+  client.revenue_share_claimed = Some(client.revenue_share_claimed.unwrap_or_default() + amount);
+
+  CLIENTS.save(deps.storage, client_address.clone(), &client)?;
+
+  let pool = POOL.load(deps.storage)?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("client_address", client_address.to_string()),
+        attr("amount", amount.to_string()),
+      ])
+      .add_event(
+        ClientRevenueShareClaimEvent {
+          client: client_address,
+          recipient: recipient.clone(),
+          amount,
+        }
+        .to_event(),
+      )
+      .add_submessage(build_send_submsg(&recipient, amount, &pool.token)?),
+  )
+}
@@ -1,11 +1,12 @@
 use crate::{
   error::{ContractError, ContractResult},
-  models::Client,
+  events::{ClientEvent, ClientEventKind, ToEvent},
+  models::{Client, HouseEvent},
   msg::ClientInitArgs,
-  state::{ensure_sender_is_allowed, CLIENTS, CONFIG, N_CLIENTS},
+  state::{ensure_sender_is_allowed, log_event, validate_address, CLIENTS, CONFIG, N_CLIENTS},
   utils::increment,
 };
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
 
 pub fn connect(
   deps: DepsMut,
@@ -18,36 +19,58 @@ pub fn connect(
 
   ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/clients/connect")?;
 
-  if let Some(addr) = &init_args.address {
-    // save the client or error if already exists
-    CLIENTS.update(
-      deps.storage,
-      addr.clone(),
-      |maybe_client| -> ContractResult<_> {
-        if maybe_client.is_some() {
-          // already connected
-          Err(ContractError::NotAuthorized {})
-        } else {
-          Ok(Client::new(
-            env.block.time,
-            None,
-            init_args.budget,
-            init_args.name,
-            init_args.description,
-            init_args.url,
-            init_args
-              .rate_limit
-              .unwrap_or(config.default_client_rate_limit.clone()),
-          ))
-        }
-      },
-    )?;
-    // increment the client counter
-    increment(deps.storage, &N_CLIENTS, 1)?;
-  } else {
-    // missing address
-    return Err(ContractError::NotAuthorized {});
+  let addr = init_args
+    .address
+    .clone()
+    .ok_or(ContractError::MissingClientAddress)?;
+
+  validate_address(deps.api, &addr)?;
+
+  if init_args.budget == Some(Uint128::zero()) {
+    return Err(ContractError::InvalidClientBudget);
   }
 
-  Ok(Response::new().add_attributes(vec![attr("action", action)]))
+  let client = Client::new(
+    env.block.time,
+    None,
+    init_args.budget,
+    init_args.name,
+    init_args.description,
+    init_args.url,
+    init_args
+      .rate_limit
+      .unwrap_or(config.default_client_rate_limit.clone()),
+  );
+
+  client.config.validate()?;
+
+  // save the client or error if already exists
+  CLIENTS.update(
+    deps.storage,
+    addr.clone(),
+    |maybe_client| -> ContractResult<_> {
+      if maybe_client.is_some() {
+        Err(ContractError::ClientAlreadyConnected)
+      } else {
+        Ok(client)
+      }
+    },
+  )?;
+
+  // increment the client counter
+  increment(deps.storage, &N_CLIENTS, 1)?;
+
+  log_event(
+    deps.storage,
+    HouseEvent::ClientConnected {
+      block: env.block.clone(),
+      client: addr.clone(),
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action)])
+      .add_event(ClientEvent::new(ClientEventKind::Connected, &addr, None).to_event()),
+  )
 }
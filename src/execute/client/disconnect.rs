@@ -1,13 +1,18 @@
 use crate::{
   error::ContractResult,
-  state::{ensure_sender_is_allowed, CLIENTS, N_CLIENTS},
+  events::{ClientEvent, ClientEventKind, ClientRevenueShareClaimEvent, ToEvent},
+  models::HouseEvent,
+  state::{ensure_sender_is_allowed, log_event, CLIENTS, N_CLIENTS, POOL},
   utils::decrement,
 };
 use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response};
+use cw_lib::utils::funds::build_send_submsg;
 
+/// Disconnect a client, paying out any unclaimed revenue share to its payout
+/// address, since that balance is no longer part of the pool's liquidity.
 pub fn disconnect(
   deps: DepsMut,
-  _env: Env,
+  env: Env,
   info: MessageInfo,
   client_address: Addr,
 ) -> ContractResult<Response> {
@@ -15,10 +20,42 @@ pub fn disconnect(
 
   ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/clients/disconnect")?;
 
-  if CLIENTS.has(deps.storage, client_address.clone()) {
+  let mut resp = Response::new().add_attributes(vec![attr("action", action)]);
+
+  if let Some(client) = CLIENTS.may_load(deps.storage, client_address.clone())? {
     CLIENTS.remove(deps.storage, client_address.clone());
     decrement(deps.storage, &N_CLIENTS, 1)?;
+    log_event(
+      deps.storage,
+      HouseEvent::ClientDisconnected {
+        block: env.block.clone(),
+        client: client_address.clone(),
+      },
+    )?;
+    resp = resp
+      .add_event(ClientEvent::new(ClientEventKind::Disconnected, &client_address, None).to_event());
+
+    let amount = client.revenue_share_balance.unwrap_or_default();
+    if !amount.is_zero() {
+      let recipient = client
+        .config
+        .payout_address
+        .clone()
+        .unwrap_or(client_address.clone());
+      let token = POOL.load(deps.storage)?.token;
+      resp = resp
+        .add_attribute("revenue_share_paid_out", amount.to_string())
+        .add_event(
+          ClientRevenueShareClaimEvent {
+            client: client_address.clone(),
+            recipient: recipient.clone(),
+            amount,
+          }
+          .to_event(),
+        )
+        .add_submessage(build_send_submsg(&recipient, amount, &token)?);
+    }
   }
 
-  Ok(Response::new().add_attributes(vec![attr("action", action)]))
+  Ok(resp)
 }
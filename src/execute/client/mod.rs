@@ -1,9 +1,11 @@
+mod claim_revenue_share;
 mod connect;
 mod disconnect;
 mod resume;
 mod set_config;
 mod suspend;
 
+pub use claim_revenue_share::claim_revenue_share;
 pub use connect::connect;
 pub use disconnect::disconnect;
 pub use resume::resume;
@@ -1,7 +1,8 @@
 use crate::{
   error::{ContractError, ContractResult},
-  models::Usage,
-  state::{ensure_sender_is_allowed, CLIENTS, POOL, USAGE},
+  events::{ClientEvent, ClientEventKind, ToEvent},
+  models::{HouseEvent, Usage},
+  state::{ensure_sender_is_allowed, log_event, CLIENTS, POOL, USAGE},
 };
 use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response, Uint128};
 
@@ -24,8 +25,7 @@ pub fn resume(
         client.is_suspended = false;
         Ok(client)
       } else {
-        // client not found
-        Err(ContractError::NotAuthorized {})
+        Err(ContractError::ClientNotFound)
       }
     },
   )?;
@@ -43,5 +43,17 @@ pub fn resume(
     },
   )?;
 
-  Ok(Response::new().add_attributes(vec![attr("action", action)]))
+  log_event(
+    deps.storage,
+    HouseEvent::ClientResumed {
+      block: env.block.clone(),
+      client: client_address.clone(),
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action)])
+      .add_event(ClientEvent::new(ClientEventKind::Resumed, &client_address, None).to_event()),
+  )
 }
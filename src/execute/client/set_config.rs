@@ -1,7 +1,8 @@
 use crate::{
-  error::{ContractError, ContractResult},
-  models::ClientConfig,
-  state::{ensure_sender_is_allowed, CLIENTS},
+  error::ContractResult,
+  events::{ClientEvent, ClientEventKind, ToEvent},
+  msg::{ClientConfigUpdates, OptionalUpdate},
+  state::{ensure_sender_is_allowed, load_client, validate_address, CLIENTS},
 };
 use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response};
 
@@ -10,27 +11,32 @@ pub fn set_client_config(
   _env: Env,
   info: MessageInfo,
   client_address: Addr,
-  config: ClientConfig,
+  updates: ClientConfigUpdates,
 ) -> ContractResult<Response> {
   let action = "set_client_config";
 
   ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/clients/set-config")?;
 
-  CLIENTS.update(
-    deps.storage,
-    client_address.clone(),
-    |maybe_client| -> ContractResult<_> {
-      if let Some(mut client) = maybe_client {
-        client.config = config;
-        Ok(client)
-      } else {
-        Err(ContractError::ClientNotFound)
-      }
-    },
-  )?;
+  if let Some(OptionalUpdate::Set(addr)) = &updates.payout_address {
+    validate_address(deps.api, addr)?;
+  }
 
-  Ok(Response::new().add_attributes(vec![
-    attr("action", action),
-    attr("client_address", client_address.to_string()),
-  ]))
+  let mut client = load_client(deps.storage, &client_address)?;
+  let change_attrs = client.config.apply_updates(&updates)?;
+
+  client.config.validate()?;
+
+  CLIENTS.save(deps.storage, client_address.clone(), &client)?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("client_address", client_address.to_string()),
+      ])
+      .add_attributes(change_attrs)
+      .add_event(
+        ClientEvent::new(ClientEventKind::ConfigUpdated, &client_address, None).to_event(),
+      ),
+  )
 }
@@ -1,12 +1,15 @@
 use crate::{
   error::ContractResult,
-  state::{ensure_sender_is_allowed, suspend_client},
+  events::{ClientEvent, ClientEventKind, ToEvent},
+  models::HouseEvent,
+  msg::HookPayload,
+  state::{build_hook_submsgs, ensure_sender_is_allowed, log_event, suspend_client},
 };
 use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response};
 
 pub fn suspend(
   deps: DepsMut,
-  _env: Env,
+  env: Env,
   info: MessageInfo,
   client_address: Addr,
 ) -> ContractResult<Response> {
@@ -15,5 +18,27 @@ pub fn suspend(
   ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/clients/suspend")?;
   suspend_client(deps.storage, &client_address)?;
 
-  Ok(Response::new().add_attributes(vec![attr("action", action)]))
+  log_event(
+    deps.storage,
+    HouseEvent::ClientSuspended {
+      block: env.block.clone(),
+      client: client_address.clone(),
+      reason: "owner".to_owned(),
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action)])
+      .add_event(
+        ClientEvent::new(ClientEventKind::Suspended, &client_address, Some("owner")).to_event(),
+      )
+      .add_submessages(build_hook_submsgs(
+        deps.storage,
+        &HookPayload::ClientSuspended {
+          client: client_address.clone(),
+          reason: "owner".to_owned(),
+        },
+      )?),
+  )
 }
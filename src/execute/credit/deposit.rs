@@ -1,6 +1,8 @@
 use crate::{
   error::{ContractError, ContractResult},
-  state::{amortize, ensure_has_funds, BANK_ACCOUNTS, POOL},
+  events::{CreditEvent, CreditEventKind, ToEvent},
+  models::PauseScope,
+  state::{amortize, ensure_has_funds, ensure_not_paused, BANK_ACCOUNTS, POOL},
 };
 use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
 use cw_lib::{models::Token, utils::funds::build_cw20_transfer_from_submsg};
@@ -11,6 +13,8 @@ pub fn deposit(
   info: MessageInfo,
   amount: Uint128,
 ) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Credit)?;
+
   let action = "deposit";
 
   if amount.is_zero() {
@@ -18,7 +22,7 @@ pub fn deposit(
     return Err(ContractError::NotAuthorized {});
   }
 
-  BANK_ACCOUNTS.update(
+  let account = BANK_ACCOUNTS.update(
     deps.storage,
     info.sender.clone(),
     |maybe_account| -> ContractResult<_> {
@@ -32,10 +36,20 @@ pub fn deposit(
   )?;
 
   let pool = POOL.load(deps.storage)?;
-  let mut resp = Response::new().add_attributes(vec![
-    attr("action", action),
-    attr("amount", amount.to_string()),
-  ]);
+  let mut resp = Response::new()
+    .add_attributes(vec![
+      attr("action", action),
+      attr("amount", amount.to_string()),
+    ])
+    .add_event(
+      CreditEvent {
+        kind: CreditEventKind::Deposit,
+        account: info.sender.clone(),
+        amount,
+        balance: account.balance,
+      }
+      .to_event(),
+    );
 
   // validate and take payment
   match &pool.token {
@@ -54,7 +68,7 @@ pub fn deposit(
     },
   }
 
-  amortize(deps.storage, deps.api)?;
+  amortize(deps.storage, deps.api, env.block.time)?;
 
   Ok(resp)
 }
@@ -1,23 +1,30 @@
 use crate::{
   error::{ContractError, ContractResult},
-  state::{amortize, BANK_ACCOUNTS},
+  events::{CreditEvent, CreditEventKind, ToEvent},
+  models::PauseScope,
+  state::{amortize, ensure_not_paused, BANK_ACCOUNTS},
 };
 use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
 
 pub fn withdraw(
   deps: DepsMut,
-  _env: Env,
+  env: Env,
   info: MessageInfo,
   amount: Option<Uint128>,
 ) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Credit)?;
+
   let action = "withdraw";
 
-  BANK_ACCOUNTS.update(
+  let mut withdrawn = Uint128::zero();
+
+  let account = BANK_ACCOUNTS.update(
     deps.storage,
     info.sender.clone(),
     |maybe_account| -> ContractResult<_> {
       if let Some(mut account) = maybe_account {
         let amount = amount.unwrap_or(account.balance);
+        withdrawn = amount;
         if account.balance < amount {
           // invalid amount
           return Err(ContractError::NotAuthorized {});
@@ -30,7 +37,22 @@ pub fn withdraw(
     },
   )?;
 
-  amortize(deps.storage, deps.api)?;
+  amortize(deps.storage, deps.api, env.block.time)?;
 
-  Ok(Response::new().add_attributes(vec![attr("action", action)]))
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("amount", withdrawn.to_string()),
+      ])
+      .add_event(
+        CreditEvent {
+          kind: CreditEventKind::Withdraw,
+          account: info.sender.clone(),
+          amount: withdrawn,
+          balance: account.balance,
+        }
+        .to_event(),
+      ),
+  )
 }
@@ -0,0 +1,74 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{GovernanceEvent, GovernanceEventKind, ToEvent},
+  models::{ProposalAction, ProposalStatus},
+  msg::{ClientMsg, ExecuteMsg},
+  state::PROPOSALS,
+};
+use cosmwasm_std::{
+  attr, to_binary, DepsMut, Env, MessageInfo, Response, Uint128, Uint64, WasmMsg,
+};
+
+/// Execute a passed proposal. Each action is dispatched by the house to itself,
+/// so it goes through the same handler, authorization and timelock as when the
+/// owner performs it. For this to succeed, the house must either be its own
+/// owner or be granted the corresponding paths by the owner's ACL. Anyone may
+/// execute a passed proposal.
+pub fn execute(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  id: Uint64,
+) -> ContractResult<Response> {
+  let action = "execute_proposal";
+  let mut proposal = PROPOSALS
+    .may_load(deps.storage, id.u64())?
+    .ok_or(ContractError::ProposalNotFound)?;
+
+  if proposal.status(env.block.time) != ProposalStatus::Passed {
+    return Err(ContractError::ProposalNotPassed);
+  }
+
+  proposal.executed = true;
+  PROPOSALS.save(deps.storage, id.u64(), &proposal)?;
+
+  let mut resp = Response::new()
+    .add_attributes(vec![
+      attr("action", action),
+      attr("proposal_id", id.to_string()),
+    ])
+    .add_event(
+      GovernanceEvent {
+        kind: GovernanceEventKind::ProposalExecuted,
+        proposal_id: id,
+        sender: info.sender.clone(),
+        vote: None,
+        power: Uint128::zero(),
+      }
+      .to_event(),
+    );
+
+  for proposal_action in proposal.actions.iter() {
+    let msg = match proposal_action {
+      ProposalAction::SetConfig(updates) => ExecuteMsg::SetConfig {
+        config: updates.clone(),
+      },
+      ProposalAction::SetTaxes(recipients) => ExecuteMsg::SetTaxes {
+        recipients: recipients.clone(),
+      },
+      ProposalAction::Connect(init_args) => {
+        ExecuteMsg::Client(ClientMsg::Connect(init_args.clone()))
+      },
+      ProposalAction::Disconnect(address) => ExecuteMsg::Client(ClientMsg::Disconnect {
+        address: address.clone(),
+      }),
+    };
+    resp = resp.add_message(WasmMsg::Execute {
+      contract_addr: env.contract.address.to_string(),
+      msg: to_binary(&msg)?,
+      funds: vec![],
+    });
+  }
+
+  Ok(resp)
+}
@@ -0,0 +1,7 @@
+mod execute;
+mod propose;
+mod vote;
+
+pub use execute::execute;
+pub use propose::propose;
+pub use vote::vote;
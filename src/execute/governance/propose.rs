@@ -0,0 +1,109 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{GovernanceEvent, GovernanceEventKind, ToEvent},
+  models::{Proposal, ProposalAction},
+  state::{
+    validate_address, validate_tax_recipients, voting_power_at, CONFIG, PROPOSALS, PROPOSAL_SEQ_NO,
+  },
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
+
+/// Create a proposal for stakers to vote on. The proposer's voting power and
+/// the total voting power are taken from delegation snapshots at the start of
+/// the current block.
+pub fn propose(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  title: String,
+  description: Option<String>,
+  actions: Vec<ProposalAction>,
+) -> ContractResult<Response> {
+  let action = "propose";
+  let config = CONFIG.load(deps.storage)?;
+  let governance = config
+    .governance
+    .clone()
+    .ok_or(ContractError::GovernanceDisabled)?;
+
+  if title.is_empty() || actions.is_empty() {
+    return Err(ContractError::InvalidProposal);
+  }
+
+  // validate actions up front so that a passed proposal can be executed
+  for proposal_action in actions.iter() {
+    match proposal_action {
+      ProposalAction::SetConfig(updates) => {
+        let mut updated_config = config.clone();
+        updated_config.apply_updates(updates);
+        updated_config.validate()?;
+      },
+      ProposalAction::SetTaxes(recipients) => {
+        validate_tax_recipients(deps.api, recipients)?;
+      },
+      ProposalAction::Connect(init_args) => {
+        let addr = init_args
+          .address
+          .clone()
+          .ok_or(ContractError::MissingClientAddress)?;
+        validate_address(deps.api, &addr)?;
+      },
+      ProposalAction::Disconnect(addr) => {
+        validate_address(deps.api, addr)?;
+      },
+    }
+  }
+
+  let height = env.block.height;
+  let (power, total_power) = voting_power_at(deps.storage, &info.sender, height)?;
+  let min_power = governance.min_proposer_power.unwrap_or(Uint128::one());
+  if power.is_zero() || power < min_power {
+    return Err(ContractError::InsufficientVotingPower);
+  }
+
+  let id = PROPOSAL_SEQ_NO.load(deps.storage)? + 1;
+  PROPOSAL_SEQ_NO.save(deps.storage, &id)?;
+
+  PROPOSALS.save(
+    deps.storage,
+    id,
+    &Proposal {
+      id: id.into(),
+      proposer: info.sender.clone(),
+      title,
+      description,
+      actions,
+      snapshot_height: height.into(),
+      total_power,
+      quorum: governance.quorum,
+      threshold: governance.threshold,
+      created_at: env.block.time,
+      ends_at: env
+        .block
+        .time
+        .plus_seconds(governance.voting_period_seconds.u64()),
+      yes: Uint128::zero(),
+      no: Uint128::zero(),
+      abstain: Uint128::zero(),
+      executed: false,
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("proposal_id", id.to_string()),
+      ])
+      .add_event(
+        GovernanceEvent {
+          kind: GovernanceEventKind::ProposalCreated,
+          proposal_id: id.into(),
+          sender: info.sender.clone(),
+          vote: None,
+          power,
+        }
+        .to_event(),
+      ),
+  )
+}
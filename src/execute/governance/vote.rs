@@ -0,0 +1,71 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{GovernanceEvent, GovernanceEventKind, ToEvent},
+  models::{Ballot, ProposalStatus, Vote},
+  state::{voting_power_at, BALLOTS, PROPOSALS},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint64};
+
+/// Cast the sender's vote on an open proposal, weighted by their delegation at
+/// the proposal's snapshot height. Votes are final.
+pub fn vote(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  id: Uint64,
+  vote: Vote,
+) -> ContractResult<Response> {
+  let action = "vote";
+  let mut proposal = PROPOSALS
+    .may_load(deps.storage, id.u64())?
+    .ok_or(ContractError::ProposalNotFound)?;
+
+  if proposal.status(env.block.time) != ProposalStatus::Open {
+    return Err(ContractError::ProposalNotOpen);
+  }
+
+  if BALLOTS.has(deps.storage, (id.u64(), info.sender.clone())) {
+    return Err(ContractError::AlreadyVoted);
+  }
+
+  let (power, _) = voting_power_at(deps.storage, &info.sender, proposal.snapshot_height.u64())?;
+  if power.is_zero() {
+    return Err(ContractError::InsufficientVotingPower);
+  }
+
+  match vote {
+    Vote::Yes => proposal.yes += power,
+    Vote::No => proposal.no += power,
+    Vote::Abstain => proposal.abstain += power,
+  }
+
+  PROPOSALS.save(deps.storage, id.u64(), &proposal)?;
+  BALLOTS.save(
+    deps.storage,
+    (id.u64(), info.sender.clone()),
+    &Ballot {
+      vote: vote.clone(),
+      power,
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("proposal_id", id.to_string()),
+        attr("vote", vote.as_str()),
+        attr("power", power.to_string()),
+      ])
+      .add_event(
+        GovernanceEvent {
+          kind: GovernanceEventKind::Voted,
+          proposal_id: id,
+          sender: info.sender.clone(),
+          vote: Some(vote),
+          power,
+        }
+        .to_event(),
+      ),
+  )
+}
@@ -0,0 +1,48 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  models::{Hook, HookTrigger},
+  state::{ensure_sender_is_allowed, validate_address, HOOKS, MAX_HOOKS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Order, Response, Uint128};
+
+/// Subscribe a contract to the given triggers, replacing any existing
+/// subscription for the same address.
+pub fn add(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  address: Addr,
+  triggers: Vec<HookTrigger>,
+  loss_threshold: Option<Uint128>,
+  gas_limit: Option<u64>,
+) -> ContractResult<Response> {
+  let action = "add_hook";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/hooks/add")?;
+  validate_address(deps.api, &address)?;
+
+  if !HOOKS.has(deps.storage, address.clone())
+    && HOOKS
+      .keys(deps.storage, None, None, Order::Ascending)
+      .count()
+      >= MAX_HOOKS
+  {
+    return Err(ContractError::TooManyHooks);
+  }
+
+  HOOKS.save(
+    deps.storage,
+    address.clone(),
+    &Hook {
+      address: None,
+      triggers,
+      loss_threshold,
+      gas_limit,
+    },
+  )?;
+
+  Ok(Response::new().add_attributes(vec![
+    attr("action", action),
+    attr("hook_address", address.to_string()),
+  ]))
+}
@@ -0,0 +1,5 @@
+mod add;
+mod remove;
+
+pub use add::add;
+pub use remove::remove;
@@ -0,0 +1,27 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  state::{ensure_sender_is_allowed, HOOKS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn remove(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  address: Addr,
+) -> ContractResult<Response> {
+  let action = "remove_hook";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/hooks/remove")?;
+
+  if !HOOKS.has(deps.storage, address.clone()) {
+    return Err(ContractError::HookNotFound);
+  }
+
+  HOOKS.remove(deps.storage, address.clone());
+
+  Ok(Response::new().add_attributes(vec![
+    attr("action", action),
+    attr("hook_address", address.to_string()),
+  ]))
+}
@@ -0,0 +1,54 @@
+use crate::{
+  error::ContractResult,
+  events::{InitiatorListEvent, InitiatorListEventKind, ToEvent},
+  models::InitiatorListEntry,
+  state::{ensure_sender_is_allowed, load_client, validate_address, ALLOWED_INITIATORS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response, Timestamp};
+
+/// Add an initiator to a client's allowlist, which only applies while the
+/// client is in allowlist-only mode.
+pub fn allow(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  client_address: Addr,
+  address: Addr,
+  reason: Option<String>,
+  expires_at: Option<Timestamp>,
+) -> ContractResult<Response> {
+  let action = "allow_initiator";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/initiators/allow")?;
+  validate_address(deps.api, &address)?;
+  load_client(deps.storage, &client_address)?;
+
+  ALLOWED_INITIATORS.save(
+    deps.storage,
+    (client_address.clone(), address.clone()),
+    &InitiatorListEntry {
+      reason: reason.clone(),
+      created_at: env.block.time,
+      expires_at,
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("client", client_address.to_string()),
+        attr("initiator", address.to_string()),
+      ])
+      .add_event(
+        InitiatorListEvent {
+          kind: InitiatorListEventKind::Allowed,
+          initiator: address,
+          client: Some(client_address),
+          reason,
+          expires_at,
+        }
+        .to_event(),
+      ),
+  )
+}
@@ -0,0 +1,50 @@
+use crate::{
+  error::ContractResult,
+  events::{InitiatorListEvent, InitiatorListEventKind, ToEvent},
+  models::InitiatorListEntry,
+  state::{ensure_sender_is_allowed, validate_address, DENIED_INITIATORS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response, Timestamp};
+
+/// Ban an initiator from all clients until the optional expiry.
+pub fn deny(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  address: Addr,
+  reason: Option<String>,
+  expires_at: Option<Timestamp>,
+) -> ContractResult<Response> {
+  let action = "deny_initiator";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/initiators/deny")?;
+  validate_address(deps.api, &address)?;
+
+  DENIED_INITIATORS.save(
+    deps.storage,
+    address.clone(),
+    &InitiatorListEntry {
+      reason: reason.clone(),
+      created_at: env.block.time,
+      expires_at,
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("initiator", address.to_string()),
+      ])
+      .add_event(
+        InitiatorListEvent {
+          kind: InitiatorListEventKind::Denied,
+          initiator: address,
+          client: None,
+          reason,
+          expires_at,
+        }
+        .to_event(),
+      ),
+  )
+}
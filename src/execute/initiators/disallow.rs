@@ -0,0 +1,45 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{InitiatorListEvent, InitiatorListEventKind, ToEvent},
+  state::{ensure_sender_is_allowed, ALLOWED_INITIATORS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn disallow(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  client_address: Addr,
+  address: Addr,
+) -> ContractResult<Response> {
+  let action = "disallow_initiator";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/initiators/disallow")?;
+
+  let key = (client_address.clone(), address.clone());
+
+  if !ALLOWED_INITIATORS.has(deps.storage, key.clone()) {
+    return Err(ContractError::InitiatorNotListed);
+  }
+
+  ALLOWED_INITIATORS.remove(deps.storage, key);
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("client", client_address.to_string()),
+        attr("initiator", address.to_string()),
+      ])
+      .add_event(
+        InitiatorListEvent {
+          kind: InitiatorListEventKind::Disallowed,
+          initiator: address,
+          client: Some(client_address),
+          reason: None,
+          expires_at: None,
+        }
+        .to_event(),
+      ),
+  )
+}
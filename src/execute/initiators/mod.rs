@@ -0,0 +1,9 @@
+mod allow;
+mod deny;
+mod disallow;
+mod undeny;
+
+pub use allow::allow;
+pub use deny::deny;
+pub use disallow::disallow;
+pub use undeny::undeny;
@@ -0,0 +1,41 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{InitiatorListEvent, InitiatorListEventKind, ToEvent},
+  state::{ensure_sender_is_allowed, DENIED_INITIATORS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn undeny(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  address: Addr,
+) -> ContractResult<Response> {
+  let action = "undeny_initiator";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/initiators/undeny")?;
+
+  if !DENIED_INITIATORS.has(deps.storage, address.clone()) {
+    return Err(ContractError::InitiatorNotListed);
+  }
+
+  DENIED_INITIATORS.remove(deps.storage, address.clone());
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("initiator", address.to_string()),
+      ])
+      .add_event(
+        InitiatorListEvent {
+          kind: InitiatorListEventKind::Undenied,
+          initiator: address,
+          client: None,
+          reason: None,
+          expires_at: None,
+        }
+        .to_event(),
+      ),
+  )
+}
@@ -0,0 +1,68 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{JackpotEvent, JackpotEventKind, ToEvent},
+  models::Jackpot,
+  state::{ensure_sender_is_allowed, load_client, JACKPOTS, MAX_JACKPOTS},
+  utils::PCT_DENOMINATOR,
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Order, Response, Uint128};
+
+/// Create a jackpot sub-pool fed by the given percentage of revenue from the
+/// given client, or from every client if no client is given.
+pub fn create(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  name: String,
+  client: Option<Addr>,
+  contribution_pct: Uint128,
+) -> ContractResult<Response> {
+  let action = "create_jackpot";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/jackpots/create")?;
+
+  if contribution_pct > Uint128::from(PCT_DENOMINATOR) {
+    return Err(ContractError::InvalidJackpotContributionPct);
+  }
+  if let Some(client_address) = &client {
+    load_client(deps.storage, client_address)?;
+  }
+  if JACKPOTS.has(deps.storage, name.clone()) {
+    return Err(ContractError::JackpotAlreadyExists);
+  }
+  if JACKPOTS
+    .keys(deps.storage, None, None, Order::Ascending)
+    .count()
+    >= MAX_JACKPOTS
+  {
+    return Err(ContractError::TooManyJackpots);
+  }
+
+  JACKPOTS.save(
+    deps.storage,
+    name.clone(),
+    &Jackpot {
+      name: name.clone(),
+      client,
+      contribution_pct,
+      balance: Uint128::zero(),
+      contributed: Uint128::zero(),
+      paid_out: Uint128::zero(),
+      created_at: env.block.time,
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action), attr("name", name.clone())])
+      .add_event(
+        JackpotEvent {
+          kind: JackpotEventKind::Created,
+          name,
+          amount: Uint128::zero(),
+          recipient: None,
+        }
+        .to_event(),
+      ),
+  )
+}
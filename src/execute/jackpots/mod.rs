@@ -0,0 +1,7 @@
+mod create;
+mod remove;
+mod set_contribution_pct;
+
+pub use create::create;
+pub use remove::remove;
+pub use set_contribution_pct::set_contribution_pct;
@@ -0,0 +1,52 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{JackpotEvent, JackpotEventKind, ToEvent},
+  state::{ensure_sender_is_allowed, JACKPOTS, JACKPOT_CONTRIBUTIONS, JACKPOT_PAYOUTS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Order, Response, StdResult, Uint128};
+
+/// Remove a jackpot, which must have been fully paid out first.
+pub fn remove(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  name: String,
+) -> ContractResult<Response> {
+  let action = "remove_jackpot";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/jackpots/remove")?;
+
+  let jackpot = JACKPOTS
+    .may_load(deps.storage, name.clone())?
+    .ok_or(ContractError::JackpotNotFound)?;
+
+  if !jackpot.balance.is_zero() {
+    return Err(ContractError::JackpotNotEmpty);
+  }
+
+  let client_addrs = JACKPOT_CONTRIBUTIONS
+    .prefix(name.clone())
+    .keys(deps.storage, None, None, Order::Ascending)
+    .collect::<StdResult<Vec<Addr>>>()?;
+
+  for client_addr in client_addrs.iter() {
+    JACKPOT_CONTRIBUTIONS.remove(deps.storage, (name.clone(), client_addr.clone()));
+    JACKPOT_PAYOUTS.remove(deps.storage, (name.clone(), client_addr.clone()));
+  }
+
+  JACKPOTS.remove(deps.storage, name.clone());
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action), attr("name", name.clone())])
+      .add_event(
+        JackpotEvent {
+          kind: JackpotEventKind::Removed,
+          name,
+          amount: Uint128::zero(),
+          recipient: None,
+        }
+        .to_event(),
+      ),
+  )
+}
@@ -0,0 +1,56 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{JackpotEvent, JackpotEventKind, ToEvent},
+  state::{ensure_sender_is_allowed, JACKPOTS},
+  utils::{change_attrs, PCT_DENOMINATOR},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn set_contribution_pct(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  name: String,
+  contribution_pct: Uint128,
+) -> ContractResult<Response> {
+  let action = "set_jackpot_contribution_pct";
+
+  ensure_sender_is_allowed(
+    &deps.as_ref(),
+    &info.sender,
+    "/house/jackpots/set-contribution-pct",
+  )?;
+
+  if contribution_pct > Uint128::from(PCT_DENOMINATOR) {
+    return Err(ContractError::InvalidJackpotContributionPct);
+  }
+
+  let mut jackpot = JACKPOTS
+    .may_load(deps.storage, name.clone())?
+    .ok_or(ContractError::JackpotNotFound)?;
+
+  let attrs = change_attrs(
+    "contribution_pct",
+    jackpot.contribution_pct.to_string(),
+    contribution_pct.to_string(),
+  );
+
+  jackpot.contribution_pct = contribution_pct;
+
+  JACKPOTS.save(deps.storage, name.clone(), &jackpot)?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action), attr("name", name.clone())])
+      .add_attributes(attrs)
+      .add_event(
+        JackpotEvent {
+          kind: JackpotEventKind::Updated,
+          name,
+          amount: jackpot.balance,
+          recipient: None,
+        }
+        .to_event(),
+      ),
+  )
+}
@@ -1,17 +1,32 @@
 pub mod client;
 pub mod credit;
+pub mod governance;
+pub mod hooks;
+pub mod initiators;
+pub mod jackpots;
+pub mod player;
 pub mod pool;
 pub mod process;
 pub mod receive;
+pub mod referrals;
+pub mod timelock;
 
+mod accept_ownership;
+mod cancel_ownership_transfer;
+mod pause;
 mod pay_taxes;
+mod propose_owner;
 mod set_config;
-mod set_owner;
 mod set_taxes;
+mod unpause;
 
+pub use accept_ownership::accept_ownership;
+pub use cancel_ownership_transfer::cancel_ownership_transfer;
+pub use pause::pause;
 pub use pay_taxes::pay_taxes;
 pub use process::{process_many, process_one};
+pub use propose_owner::propose_owner;
 pub use receive::receive;
 pub use set_config::set_config;
-pub use set_owner::set_owner;
 pub use set_taxes::set_taxes;
+pub use unpause::unpause;
@@ -0,0 +1,36 @@
+use crate::{
+  error::ContractResult,
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  models::PauseScope,
+  state::{ensure_sender_is_allowed, PAUSED_SCOPES},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+pub fn pause(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  scopes: Vec<PauseScope>,
+) -> ContractResult<Response> {
+  let action = "pause";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/pause")?;
+
+  let paused_scopes = PAUSED_SCOPES.update(deps.storage, |mut paused| -> ContractResult<_> {
+    for scope in scopes.iter() {
+      if !paused.contains(scope) {
+        paused.push(scope.clone());
+      }
+    }
+    Ok(paused)
+  })?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("n_paused_scopes", paused_scopes.len().to_string()),
+      ])
+      .add_event(AdminEvent::new(AdminEventKind::Paused, &info.sender).to_event()),
+  )
+}
@@ -1,14 +1,16 @@
 use crate::{
   error::ContractResult,
-  state::{ensure_sender_is_allowed, POOL, TAX_RECIPIENTS},
+  events::{TaxPaymentEvent, ToEvent},
+  models::{HouseEvent, Tranche},
+  state::{ensure_sender_is_allowed, log_event, snapshot_pool, POOL, TAX_RECIPIENTS},
   utils::mul_pct,
 };
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Order, Response, SubMsg, Uint128};
+use cosmwasm_std::{attr, DepsMut, Env, Event, MessageInfo, Order, Response, SubMsg, Uint128};
 use cw_lib::utils::funds::build_send_submsg;
 
 pub fn pay_taxes(
   deps: DepsMut,
-  _env: Env,
+  env: Env,
   info: MessageInfo,
 ) -> ContractResult<Response> {
   let action = "pay_taxes";
@@ -16,28 +18,53 @@ pub fn pay_taxes(
   ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/pay-taxes")?;
 
   let mut transfer_submsgs: Vec<SubMsg> = Vec::with_capacity(1);
+  let mut events: Vec<Event> = Vec::with_capacity(1);
   let mut pool = POOL.load(deps.storage)?;
+  let mut total_paid = Uint128::zero();
 
   // build the token transfer SubMsg for each tax recipient
   for result in TAX_RECIPIENTS.range(deps.storage, None, None, Order::Ascending) {
     if let Ok((addr, recipient)) = result {
       let amount = mul_pct(pool.taxes, recipient.pct.into());
       transfer_submsgs.push(build_send_submsg(&addr, amount, &pool.token)?);
+      events.push(
+        TaxPaymentEvent {
+          recipient: addr.clone(),
+          amount,
+        }
+        .to_event(),
+      );
       pool.taxes -= amount;
+      total_paid += amount;
     }
   }
 
-  // if there's any dust left over, just move it into liquidity
+  // if there's any dust left over, just move it into senior liquidity
   if !pool.taxes.is_zero() {
+    let mut senior = pool.tranche(&Tranche::Senior);
+    senior.liquidity += pool.taxes;
+    pool.set_tranche(&Tranche::Senior, senior);
     pool.liquidity += pool.taxes;
     pool.taxes = Uint128::zero();
   }
 
   POOL.save(deps.storage, &pool)?;
+  snapshot_pool(deps.storage, env.block.height, &pool)?;
+
+  if !total_paid.is_zero() {
+    log_event(
+      deps.storage,
+      HouseEvent::TaxesPaid {
+        block: env.block.clone(),
+        amount: total_paid,
+      },
+    )?;
+  }
 
   Ok(
     Response::new()
       .add_attributes(vec![attr("action", action)])
-      .add_submessages(transfer_submsgs),
+      .add_submessages(transfer_submsgs)
+      .add_events(events),
   )
 }
@@ -0,0 +1,28 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{PlayerEvent, PlayerEventKind, ToEvent},
+  state::PENDING_PLAYER_LIMITS,
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+pub fn cancel_pending_limits(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+) -> ContractResult<Response> {
+  let action = "cancel_pending_player_limits";
+
+  if !PENDING_PLAYER_LIMITS.has(deps.storage, info.sender.clone()) {
+    return Err(ContractError::NoPendingLimits);
+  }
+
+  PENDING_PLAYER_LIMITS.remove(deps.storage, info.sender.clone());
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action)])
+      .add_event(
+        PlayerEvent::new(PlayerEventKind::PendingLimitsCancelled, &info.sender).to_event(),
+      ),
+  )
+}
@@ -0,0 +1,69 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{RakebackClaimEvent, ToEvent},
+  models::PauseScope,
+  state::{ensure_not_paused, BANK_ACCOUNTS, POOL, RAKEBACK, RAKEBACK_TOTAL},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+use cw_lib::utils::funds::build_send_submsg;
+
+/// Pay out the sender's accrued rakeback, either by sending it to them or by
+/// crediting it to their existing BankAccount.
+pub fn claim_rakeback(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  to_bank_account: Option<bool>,
+) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Claims)?;
+
+  let action = "claim_rakeback";
+  let to_bank_account = to_bank_account.unwrap_or(false);
+
+  let amount = RAKEBACK
+    .may_load(deps.storage, info.sender.clone())?
+    .unwrap_or_default();
+
+  if amount.is_zero() {
+    return Err(ContractError::NothingToClaim);
+  }
+
+  RAKEBACK.remove(deps.storage, info.sender.clone());
+  RAKEBACK_TOTAL.update(deps.storage, |total| -> ContractResult<_> {
+    Ok(total - amount)
+  })?;
+
+  let mut resp = Response::new()
+    .add_attributes(vec![
+      attr("action", action),
+      attr("amount", amount.to_string()),
+    ])
+    .add_event(
+      RakebackClaimEvent {
+        player: info.sender.clone(),
+        amount,
+        to_bank_account,
+      }
+      .to_event(),
+    );
+
+  if to_bank_account {
+    BANK_ACCOUNTS.update(
+      deps.storage,
+      info.sender.clone(),
+      |maybe_account| -> ContractResult<_> {
+        if let Some(mut account) = maybe_account {
+          account.balance += amount;
+          Ok(account)
+        } else {
+          Err(ContractError::NotAuthorized {})
+        }
+      },
+    )?;
+  } else {
+    let pool = POOL.load(deps.storage)?;
+    resp = resp.add_submessage(build_send_submsg(&info.sender, amount, &pool.token)?);
+  }
+
+  Ok(resp)
+}
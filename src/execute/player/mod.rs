@@ -0,0 +1,7 @@
+mod cancel_pending_limits;
+mod claim_rakeback;
+mod set_limits;
+
+pub use cancel_pending_limits::cancel_pending_limits;
+pub use claim_rakeback::claim_rakeback;
+pub use set_limits::set_limits;
@@ -0,0 +1,61 @@
+use crate::{
+  error::ContractResult,
+  events::{PlayerEvent, PlayerEventKind, ToEvent},
+  models::{PendingPlayerLimits, PlayerLimits},
+  state::{
+    load_player_limits, CONFIG, DEFAULT_PLAYER_LIMITS_DELAY_SECONDS, PENDING_PLAYER_LIMITS,
+    PLAYER_LIMITS,
+  },
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+/// Set the sender's own responsible-gaming limits. Limits that are at least as
+/// strict as the current ones take effect immediately. Any limit that loosens
+/// the current ones is queued and only takes effect after a delay, while the
+/// stricter of the current and new limits applies in the meantime. A
+/// self-exclusion that hasn't expired can only be extended.
+pub fn set_limits(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  limits: PlayerLimits,
+) -> ContractResult<Response> {
+  let action = "set_player_limits";
+
+  limits.validate()?;
+
+  let current_limits =
+    load_player_limits(deps.storage, env.block.time, &info.sender)?.unwrap_or(PlayerLimits {
+      excluded_until: None,
+      loss_limit: None,
+      wager_limit: None,
+    });
+
+  let (tightened_limits, is_loosened) = current_limits.tighten(&limits, env.block.time);
+
+  PLAYER_LIMITS.save(deps.storage, info.sender.clone(), &tightened_limits)?;
+
+  let mut resp = Response::new().add_attributes(vec![attr("action", action)]);
+
+  if is_loosened {
+    let config = CONFIG.load(deps.storage)?;
+    let delay_seconds = config
+      .player_limits_delay_seconds
+      .map(|x| x.u64())
+      .unwrap_or(DEFAULT_PLAYER_LIMITS_DELAY_SECONDS);
+    let eta = env.block.time.plus_seconds(delay_seconds);
+    PENDING_PLAYER_LIMITS.save(
+      deps.storage,
+      info.sender.clone(),
+      &PendingPlayerLimits { limits, eta },
+    )?;
+    resp = resp
+      .add_attribute("eta", eta.seconds().to_string())
+      .add_event(PlayerEvent::new(PlayerEventKind::LimitsQueued, &info.sender).to_event());
+  } else {
+    // New limits supersede any loosening still waiting to take effect.
+    PENDING_PLAYER_LIMITS.remove(deps.storage, info.sender.clone());
+  }
+
+  Ok(resp.add_event(PlayerEvent::new(PlayerEventKind::LimitsUpdated, &info.sender).to_event()))
+}
@@ -1,6 +1,11 @@
 use crate::{
   error::ContractResult,
-  state::{load_stake_account, sync_account, N_DELEGATION_MUTATIONS, POOL, STAKE_ACCOUNTS},
+  events::{ClaimEvent, ToEvent},
+  models::PauseScope,
+  state::{
+    ensure_not_paused, load_stake_account, snapshot_stake, sync_account, N_DELEGATION_MUTATIONS,
+    POOL, STAKE_ACCOUNTS,
+  },
   utils::increment,
 };
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
@@ -8,23 +13,35 @@ use cw_lib::utils::funds::build_send_submsg;
 
 pub fn claim(
   deps: DepsMut,
-  _env: Env,
+  env: Env,
   info: MessageInfo,
 ) -> ContractResult<Response> {
-  let mut pool = POOL.load(deps.storage)?;
+  ensure_not_paused(deps.storage, PauseScope::Claims)?;
+
   let mut account = load_stake_account(deps.storage, &info.sender)?;
   let mut resp = Response::new().add_attribute("action", "claim");
 
   // TODO: instead of just is_unstaking, change to enum and have
   // SyncAction::Unstake, SyncAction::Claim, etc.
-  sync_account(deps.storage, deps.api, &mut account, true)?;
+  sync_account(deps.storage, deps.api, env.block.time, &mut account, true)?;
+
+  let mut pool = POOL.load(deps.storage)?;
 
   let claim_amount = account.dividends.clone();
+  let recipient = account.recipient(&info.sender);
 
   if !claim_amount.is_zero() {
     resp = resp
       .add_attribute("amount", claim_amount.to_string())
-      .add_submessage(build_send_submsg(&info.sender, claim_amount, &pool.token)?);
+      .add_event(
+        ClaimEvent {
+          staker: info.sender.clone(),
+          recipient: recipient.clone(),
+          amount: claim_amount,
+        }
+        .to_event(),
+      )
+      .add_submessage(build_send_submsg(&recipient, claim_amount, &pool.token)?);
   }
 
   pool.dividends -= claim_amount;
@@ -32,6 +49,7 @@ pub fn claim(
 
   POOL.save(deps.storage, &pool)?;
   STAKE_ACCOUNTS.save(deps.storage, info.sender.clone(), &account)?;
+  snapshot_stake(deps.storage, env.block.height, &info.sender, &account)?;
 
   increment(deps.storage, &N_DELEGATION_MUTATIONS, Uint128::one())?;
 
@@ -0,0 +1,81 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{RewardClaimEvent, ToEvent},
+  models::PauseScope,
+  state::{
+    ensure_not_paused, reward_token_key, sync_rewards, REWARD_ACCOUNTS, REWARD_TOKENS,
+    STAKE_ACCOUNTS,
+  },
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_lib::{models::Token, utils::funds::build_send_submsg};
+
+/// Send the sender all of their accrued rewards in the given incentive token.
+pub fn claim_rewards(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  token: Token,
+) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Claims)?;
+
+  let action = "claim_rewards";
+  let key = reward_token_key(&token);
+
+  if !REWARD_TOKENS.has(deps.storage, key.clone()) {
+    return Err(ContractError::RewardTokenNotFound);
+  }
+
+  let maybe_stake_account = STAKE_ACCOUNTS.may_load(deps.storage, info.sender.clone())?;
+  let delegation = maybe_stake_account
+    .as_ref()
+    .map(|account| account.delegation)
+    .unwrap_or_default();
+  let recipient = maybe_stake_account
+    .map(|account| account.recipient(&info.sender))
+    .unwrap_or_else(|| info.sender.clone());
+
+  sync_rewards(deps.storage, env.block.time, &info.sender, delegation)?;
+
+  let map_key = (key.clone(), info.sender.clone());
+  let mut account = REWARD_ACCOUNTS
+    .may_load(deps.storage, map_key.clone())?
+    .ok_or(ContractError::NothingToClaim)?;
+
+  let amount = account.accrued;
+  if amount.is_zero() {
+    return Err(ContractError::NothingToClaim);
+  }
+
+  account.accrued = Uint128::zero();
+  account.claimed += amount;
+  REWARD_ACCOUNTS.save(deps.storage, map_key, &account)?;
+
+  REWARD_TOKENS.update(
+    deps.storage,
+    key.clone(),
+    |maybe_reward_token| -> ContractResult<_> {
+      let mut reward_token = maybe_reward_token.ok_or(ContractError::RewardTokenNotFound)?;
+      reward_token.claimed += amount;
+      Ok(reward_token)
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("amount", amount.to_string()),
+      ])
+      .add_event(
+        RewardClaimEvent {
+          staker: info.sender.clone(),
+          recipient: recipient.clone(),
+          token: key,
+          amount,
+        }
+        .to_event(),
+      )
+      .add_submessage(build_send_submsg(&recipient, amount, &token)?),
+  )
+}
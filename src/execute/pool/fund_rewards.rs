@@ -0,0 +1,129 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{RewardStreamEvent, ToEvent},
+  models::{PauseScope, RewardStream, RewardToken},
+  state::{
+    ensure_has_funds, ensure_not_paused, reward_token_key, sync_rewards, MAX_REWARD_STREAMS,
+    MAX_REWARD_TOKENS, REWARD_STREAMS, REWARD_STREAM_SEQ_NO, REWARD_TOKENS, STAKE_ACCOUNTS,
+  },
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Order, Response, Timestamp, Uint128};
+use cw_lib::{models::Token, utils::funds::build_cw20_transfer_from_submsg};
+
+/// Add a stream that emits an incentive token to stakers, pro rata to their
+/// delegation, from start to end. Anyone can fund a reward stream.
+pub fn fund_rewards(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  token: Token,
+  amount: Uint128,
+  maybe_start: Option<Timestamp>,
+  end: Timestamp,
+) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Staking)?;
+
+  let action = "fund_rewards";
+  let start = maybe_start.unwrap_or(env.block.time);
+
+  if amount.is_zero() || start < env.block.time || end <= start {
+    return Err(ContractError::InvalidRewardStream);
+  }
+
+  if let Token::Cw20 { address } = &token {
+    deps.api.addr_validate(address.as_str())?;
+  }
+
+  if REWARD_STREAMS
+    .keys(deps.storage, None, None, Order::Ascending)
+    .count()
+    >= MAX_REWARD_STREAMS
+  {
+    return Err(ContractError::TooManyRewardStreams);
+  }
+
+  // bring existing reward tokens up to date before adding the new stream
+  let delegation = STAKE_ACCOUNTS
+    .may_load(deps.storage, info.sender.clone())?
+    .map(|account| account.delegation)
+    .unwrap_or_default();
+  sync_rewards(deps.storage, env.block.time, &info.sender, delegation)?;
+
+  let key = reward_token_key(&token);
+  let reward_token = match REWARD_TOKENS.may_load(deps.storage, key.clone())? {
+    Some(mut reward_token) => {
+      reward_token.funded += amount;
+      reward_token
+    },
+    None => {
+      if REWARD_TOKENS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count()
+        >= MAX_REWARD_TOKENS
+      {
+        return Err(ContractError::TooManyRewardTokens);
+      }
+      RewardToken {
+        token: token.clone(),
+        index: Uint128::zero(),
+        updated_at: env.block.time,
+        funded: amount,
+        distributed: Uint128::zero(),
+        claimed: Uint128::zero(),
+      }
+    },
+  };
+
+  REWARD_TOKENS.save(deps.storage, key.clone(), &reward_token)?;
+
+  let id = REWARD_STREAM_SEQ_NO.load(deps.storage)? + 1;
+  REWARD_STREAM_SEQ_NO.save(deps.storage, &id)?;
+  REWARD_STREAMS.save(
+    deps.storage,
+    id,
+    &RewardStream {
+      token: token.clone(),
+      funder: info.sender.clone(),
+      amount,
+      emitted: Uint128::zero(),
+      start,
+      end,
+    },
+  )?;
+
+  let mut resp = Response::new()
+    .add_attributes(vec![
+      attr("action", action),
+      attr("id", id.to_string()),
+      attr("amount", amount.to_string()),
+    ])
+    .add_event(
+      RewardStreamEvent {
+        id,
+        funder: info.sender.clone(),
+        token: key,
+        amount,
+        start,
+        end,
+      }
+      .to_event(),
+    );
+
+  // ensure the sender has required funds and build any necessary
+  // submsg to perform the transfer from sender to the house.
+  match &token {
+    Token::Native { denom } => {
+      ensure_has_funds(&info.funds, denom, amount)?;
+    },
+    Token::Cw20 { address } => {
+      resp = resp.add_submessage(build_cw20_transfer_from_submsg(
+        &info.sender,
+        &env.contract.address,
+        address,
+        amount,
+      )?);
+    },
+  }
+
+  Ok(resp)
+}
@@ -1,9 +1,19 @@
 mod claim;
+mod claim_rewards;
+mod fund_rewards;
+mod rebond;
+mod set_reward_recipient;
 mod stake;
+mod stake_for;
 mod unstake;
 mod withdraw;
 
 pub use claim::claim;
+pub use claim_rewards::claim_rewards;
+pub use fund_rewards::fund_rewards;
+pub use rebond::rebond;
+pub use set_reward_recipient::set_reward_recipient;
 pub use stake::stake;
+pub use stake_for::stake_for;
 pub use unstake::unstake;
 pub use withdraw::withdraw;
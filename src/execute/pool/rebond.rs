@@ -0,0 +1,142 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{RebondEvent, ToEvent},
+  models::PauseScope,
+  msg::HookPayload,
+  state::{
+    build_hook_submsgs, enqueue_for_memoization, ensure_can_stake, ensure_not_paused,
+    load_stake_account, reweight_tranche, snapshot_pool, snapshot_stake, sync_account,
+    sync_rewards, CONFIG, LEDGER_ENTRY_SEQ_NO, N_DELEGATION_MUTATIONS, N_STAKE_ACCOUNTS_UNBONDING,
+    N_STAKE_ACCOUNTS_UNBONDING_AT_RISK, POOL, STAKE_ACCOUNTS,
+  },
+  utils::{decrement, increment},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
+
+/// Cancel the sender's pending unbonding, restaking the full unbonding amount.
+pub fn rebond(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Staking)?;
+
+  let action = "rebond";
+  let mut pool = POOL.load(deps.storage)?;
+  let mut account = load_stake_account(deps.storage, &info.sender)?;
+
+  let unbonding = account
+    .unbonding
+    .clone()
+    .ok_or(ContractError::NotUnstaked)?;
+  let mut amount = unbonding.amount;
+
+  // Restore the delegation the account unstaked, not the amount it gets back,
+  // which includes gains and dividends. Unbondings that predate this only
+  // know their amount.
+  let delegation = unbonding.delegation.unwrap_or(amount);
+
+  // apply the gains and losses shared by the account's unbonding liquidity
+  // and take it out of the unbonding bucket.
+  if account.is_unbonding_at_risk() {
+    sync_account(deps.storage, deps.api, env.block.time, &mut account, true)?;
+
+    let mut unbonding_totals = pool.unbonding_totals(&account.tranche());
+    unbonding_totals.liquidity -= account.liquidity;
+    pool.set_unbonding_totals(&account.tranche(), unbonding_totals);
+    pool.liquidity -= account.liquidity;
+    pool.dividends -= account.dividends;
+
+    amount += account.liquidity + account.dividends;
+
+    decrement(deps.storage, &N_STAKE_ACCOUNTS_UNBONDING_AT_RISK, 1)?;
+  }
+
+  ensure_can_stake(
+    &deps.as_ref(),
+    &CONFIG.load(deps.storage)?,
+    &pool,
+    &info.sender,
+    Uint128::zero(),
+    delegation,
+  )?;
+
+  // the account has no delegation while unbonding, so this only brings reward
+  // indices up to date.
+  sync_rewards(
+    deps.storage,
+    env.block.time,
+    &info.sender,
+    account.delegation,
+  )?;
+
+  // Ledger entries created while unbonding either didn't count this account in
+  // their ref_count or have just been synced, so the account resumes syncing
+  // from the next entry, like a new account would.
+  account.seq_no = LEDGER_ENTRY_SEQ_NO.load(deps.storage)?;
+  account.unbonding = None;
+  account.delegation = delegation;
+  account.liquidity = amount;
+  account.dividends = Uint128::zero();
+
+  // restore the amount to the pool's and tranche's delegation and liquidity.
+  // Any dividends included in the unbonding amount are restaked.
+  let tranche = account.tranche();
+  let mut totals = pool.tranche(&tranche);
+  let prev_tranche_liquidity = totals.liquidity;
+  totals.delegation += delegation;
+  totals.liquidity += amount;
+  reweight_tranche(
+    &mut totals,
+    prev_tranche_liquidity,
+    Uint128::zero(),
+    Uint128::zero(),
+    account.liquidity,
+    account.multiplier(),
+  )?;
+  pool.set_tranche(&tranche, totals);
+  pool.delegation += delegation;
+  pool.liquidity += amount;
+
+  POOL.save(deps.storage, &pool)?;
+  STAKE_ACCOUNTS.save(deps.storage, info.sender.clone(), &account)?;
+
+  snapshot_stake(deps.storage, env.block.height, &info.sender, &account)?;
+  snapshot_pool(deps.storage, env.block.height, &pool)?;
+
+  enqueue_for_memoization(deps.storage, &info.sender)?;
+  decrement(deps.storage, &N_STAKE_ACCOUNTS_UNBONDING, 1)?;
+
+  // increment the delegation mutation counter, which lets the process method
+  // know that a new LedgerEntry should be created when nexted executed, instead
+  // of updating the existing latest entry.
+  increment(deps.storage, &N_DELEGATION_MUTATIONS, Uint128::one())?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("amount", amount.to_string()),
+      ])
+      .add_event(
+        RebondEvent {
+          staker: info.sender.clone(),
+          amount,
+          delegation: account.delegation,
+          liquidity: account.liquidity,
+          pool_delegation: pool.delegation,
+          pool_liquidity: pool.liquidity,
+        }
+        .to_event(),
+      )
+      .add_submessages(build_hook_submsgs(
+        deps.storage,
+        &HookPayload::StakingChanged {
+          staker: info.sender.clone(),
+          action: action.to_owned(),
+          delegation: account.delegation,
+          pool_delegation: pool.delegation,
+        },
+      )?),
+  )
+}
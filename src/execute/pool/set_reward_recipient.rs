@@ -0,0 +1,38 @@
+use crate::{
+  error::ContractResult,
+  events::{RewardRecipientEvent, ToEvent},
+  state::{load_stake_account, STAKE_ACCOUNTS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response};
+
+/// Set or clear the address to which the sender's claims and withdrawals are
+/// sent instead of the sender.
+pub fn set_reward_recipient(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  maybe_recipient: Option<Addr>,
+) -> ContractResult<Response> {
+  let action = "set_reward_recipient";
+  let mut account = load_stake_account(deps.storage, &info.sender)?;
+
+  account.reward_recipient = if let Some(recipient) = maybe_recipient {
+    Some(deps.api.addr_validate(recipient.as_str())?)
+  } else {
+    None
+  };
+
+  STAKE_ACCOUNTS.save(deps.storage, info.sender.clone(), &account)?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![attr("action", action)])
+      .add_event(
+        RewardRecipientEvent {
+          staker: info.sender.clone(),
+          recipient: account.reward_recipient,
+        }
+        .to_event(),
+      ),
+  )
+}
@@ -1,13 +1,16 @@
 use crate::{
   error::{ContractError, ContractResult},
-  models::StakeAccount,
+  events::{StakeEvent, ToEvent},
+  models::{Lockup, PauseScope, StakeAccount, Tranche},
+  msg::HookPayload,
   state::{
-    ensure_has_funds, sync_account, LEDGER_ENTRY_SEQ_NO, MEMOIZATION_QUEUE, N_DELEGATION_MUTATIONS,
-    N_STAKE_ACCOUNTS, POOL, STAKE_ACCOUNTS,
+    build_hook_submsgs, enqueue_for_memoization, ensure_can_stake, ensure_has_funds,
+    ensure_not_paused, reweight_tranche, snapshot_pool, snapshot_stake, sync_account, sync_rewards,
+    CONFIG, LEDGER_ENTRY_SEQ_NO, N_DELEGATION_MUTATIONS, N_STAKE_ACCOUNTS, POOL, STAKE_ACCOUNTS,
   },
   utils::increment,
 };
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response, Uint128, Uint64};
 use cw_lib::{models::Token, utils::funds::build_cw20_transfer_from_submsg};
 
 pub fn stake(
@@ -15,14 +18,48 @@ pub fn stake(
   env: Env,
   info: MessageInfo,
   amount: Uint128,
+  lockup_seconds: Option<Uint64>,
+  tranche: Option<Tranche>,
 ) -> ContractResult<Response> {
+  let staker = info.sender.clone();
+  stake_on_behalf(deps, env, info, staker, amount, lockup_seconds, tranche)
+}
+
+/// Stake the sender's funds into the given staker's account.
+pub(super) fn stake_on_behalf(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  staker: Addr,
+  amount: Uint128,
+  lockup_seconds: Option<Uint64>,
+  tranche: Option<Tranche>,
+) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Staking)?;
+
   let action = "stake";
   let seq_no = LEDGER_ENTRY_SEQ_NO.load(deps.storage)?;
   let pool = POOL.load(deps.storage)?;
+  let config = CONFIG.load(deps.storage)?;
+
+  // look up the chosen lockup tier, if any
+  let maybe_tier = if let Some(seconds) = lockup_seconds {
+    Some(
+      config
+        .lockup_tiers
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .find(|t| t.seconds == seconds)
+        .ok_or(ContractError::InvalidLockupTier)?,
+    )
+  } else {
+    None
+  };
 
   // get or create the StakeAccount
   let mut account = STAKE_ACCOUNTS
-    .may_load(deps.storage, info.sender.clone())?
+    .may_load(deps.storage, staker.clone())?
     .unwrap_or_else(|| StakeAccount::new(Uint128::zero(), seq_no));
 
   // user must first withdraw unbonded amount before staking again
@@ -30,36 +67,109 @@ pub fn stake(
     return Err(ContractError::Unbonding);
   }
 
+  ensure_can_stake(
+    &deps.as_ref(),
+    &config,
+    &pool,
+    &staker,
+    account.delegation,
+    amount,
+  )?;
+
   // if this is a new account, increment the global stake account counter and
   // add the staker's address to the memoization queue.
   if account.delegation.is_zero() {
     increment(deps.storage, &N_STAKE_ACCOUNTS, 1)?;
-    MEMOIZATION_QUEUE.push_back(deps.storage, &info.sender)?;
+    enqueue_for_memoization(deps.storage, &staker)?;
+  }
+
+  sync_account(deps.storage, deps.api, env.block.time, &mut account, true)?;
+
+  // an account's liquidity lives in a single tranche, which can only be
+  // changed once the account has fully unstaked.
+  let tranche = tranche.unwrap_or_else(|| account.tranche());
+  if !account.liquidity.is_zero() && tranche != account.tranche() {
+    return Err(ContractError::TrancheMismatch);
   }
 
-  // increment the pool's net delegation and liquidity
-  POOL.update(deps.storage, |mut pool| -> ContractResult<_> {
+  let prev_liquidity = account.liquidity;
+  let prev_multiplier = account.multiplier();
+
+  // A new lockup applies to the account's entire liquidity. An unexpired
+  // lockup can be extended or boosted but never shortened or reduced.
+  let active_lockup = account
+    .lockup
+    .clone()
+    .filter(|l| l.expires_at > env.block.time);
+  account.lockup = match (active_lockup, maybe_tier) {
+    (None, None) => None,
+    (Some(lockup), None) => Some(lockup),
+    (None, Some(tier)) => Some(Lockup {
+      expires_at: env.block.time.plus_seconds(tier.seconds.u64()),
+      multiplier: tier.multiplier,
+    }),
+    (Some(lockup), Some(tier)) => Some(Lockup {
+      expires_at: lockup
+        .expires_at
+        .max(env.block.time.plus_seconds(tier.seconds.u64())),
+      multiplier: lockup.multiplier.max(tier.multiplier),
+    }),
+  };
+
+  // accrue incentive rewards earned on the account's prior delegation
+  sync_rewards(deps.storage, env.block.time, &staker, account.delegation)?;
+
+  account.delegation += amount;
+  account.liquidity += amount;
+  account.tranche = Some(tranche.clone());
+
+  // increment the pool's and tranche's net delegation and liquidity
+  let updated_pool = POOL.update(deps.storage, |mut pool| -> ContractResult<_> {
+    let mut totals = pool.tranche(&tranche);
+    let prev_tranche_liquidity = totals.liquidity;
+    totals.delegation += amount;
+    totals.liquidity += amount;
+    reweight_tranche(
+      &mut totals,
+      prev_tranche_liquidity,
+      prev_liquidity,
+      prev_multiplier,
+      account.liquidity,
+      account.multiplier(),
+    )?;
+    pool.set_tranche(&tranche, totals);
     pool.delegation += amount;
     pool.liquidity += amount;
     Ok(pool)
   })?;
 
-  sync_account(deps.storage, deps.api, &mut account, true)?;
+  STAKE_ACCOUNTS.save(deps.storage, staker.clone(), &account)?;
 
-  account.delegation += amount;
-  account.liquidity += amount;
-
-  STAKE_ACCOUNTS.save(deps.storage, info.sender.clone(), &account)?;
+  snapshot_stake(deps.storage, env.block.height, &staker, &account)?;
+  snapshot_pool(deps.storage, env.block.height, &updated_pool)?;
 
   // increment the delegation mutation counter, which lets the process method
   // know that a new LedgerEntry should be created when nexted executed, instead
   // of updating the existing latest entry.
   increment(deps.storage, &N_DELEGATION_MUTATIONS, Uint128::one())?;
 
-  let mut resp = Response::new().add_attributes(vec![
-    attr("action", action),
-    attr("amount", amount.to_string()),
-  ]);
+  let mut resp = Response::new()
+    .add_attributes(vec![
+      attr("action", action),
+      attr("staker", staker.to_string()),
+      attr("amount", amount.to_string()),
+    ])
+    .add_event(
+      StakeEvent {
+        staker: staker.clone(),
+        amount,
+        delegation: account.delegation,
+        liquidity: account.liquidity,
+        pool_delegation: updated_pool.delegation,
+        pool_liquidity: updated_pool.liquidity,
+      }
+      .to_event(),
+    );
 
   // ensure the sender has required funds and build any necessary
   // submsg to perform the transfer from sender to the house.
@@ -79,5 +189,16 @@ pub fn stake(
     },
   }
 
+  // notify hooks subscribed to staking changes
+  resp = resp.add_submessages(build_hook_submsgs(
+    deps.storage,
+    &HookPayload::StakingChanged {
+      staker: staker.clone(),
+      action: action.to_owned(),
+      delegation: account.delegation,
+      pool_delegation: updated_pool.delegation,
+    },
+  )?);
+
   Ok(resp)
 }
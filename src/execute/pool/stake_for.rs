@@ -0,0 +1,19 @@
+use super::stake::stake_on_behalf;
+use crate::{error::ContractResult, state::validate_address};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+
+/// Stake the sender's funds on behalf of a beneficiary, who owns the resulting
+/// stake. The beneficiary's account keeps whatever lockup it has, and the
+/// stake always lands in the account's current tranche, or the senior tranche
+/// for a new account, since the sender can't choose a tranche for someone
+/// else.
+pub fn stake_for(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  beneficiary: Addr,
+  amount: Uint128,
+) -> ContractResult<Response> {
+  let beneficiary = validate_address(deps.api, &beneficiary)?;
+  stake_on_behalf(deps, env, info, beneficiary, amount, None, None)
+}
@@ -1,9 +1,12 @@
 use crate::{
   error::{ContractError, ContractResult},
-  models::UnbondingInfo,
+  events::{ToEvent, UnstakeEvent},
+  models::{PauseScope, UnbondingInfo},
+  msg::HookPayload,
   state::{
-    amortize, load_stake_account, sync_account, N_DELEGATION_MUTATIONS, N_STAKE_ACCOUNTS_UNBONDING,
-    POOL, STAKE_ACCOUNTS,
+    amortize, build_hook_submsgs, ensure_not_paused, load_stake_account, reweight_tranche,
+    snapshot_pool, snapshot_stake, sync_account, sync_rewards, CONFIG, N_DELEGATION_MUTATIONS,
+    N_STAKE_ACCOUNTS_UNBONDING, N_STAKE_ACCOUNTS_UNBONDING_AT_RISK, POOL, STAKE_ACCOUNTS,
   },
   utils::increment,
 };
@@ -14,37 +17,106 @@ pub fn unstake(
   env: Env,
   info: MessageInfo,
 ) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Unstaking)?;
+
   let action = "unstake";
-  let mut pool = POOL.load(deps.storage)?;
   let mut account = load_stake_account(deps.storage, &info.sender)?;
 
-  sync_account(deps.storage, deps.api, &mut account, true)?;
+  if let Some(lockup) = &account.lockup {
+    if env.block.time < lockup.expires_at {
+      return Err(ContractError::LockupNotExpired);
+    }
+  }
+
+  sync_account(deps.storage, deps.api, env.block.time, &mut account, true)?;
+
+  let mut pool = POOL.load(deps.storage)?;
+  sync_rewards(
+    deps.storage,
+    env.block.time,
+    &info.sender,
+    account.delegation,
+  )?;
 
   let total_amount = account.liquidity + account.dividends;
 
+  let event = UnstakeEvent {
+    staker: info.sender.clone(),
+    amount: total_amount,
+    delegation: account.delegation,
+    liquidity: account.liquidity,
+    dividends: account.dividends,
+    unbonding_time: env.block.time.seconds().into(),
+  };
+
   if !total_amount.is_zero() {
     if account.unbonding.is_some() {
       return Err(ContractError::Unbonding);
-    } else {
-      account.unbonding = Some(UnbondingInfo {
-        amount: total_amount,
-        time: env.block.time,
-      });
     }
 
-    pool.liquidity -= account.liquidity;
+    // If configured, unbonding liquidity stays in its tranche's unbonding
+    // bucket, where it still shares losses until withdrawn, so that stakers
+    // can't escape an expected loss by unstaking ahead of it. Junior liquidity
+    // remains first-loss while unbonding.
+    let at_risk = CONFIG
+      .load(deps.storage)?
+      .unbonding_shares_losses
+      .unwrap_or(false)
+      && !account.liquidity.is_zero();
+
+    account.unbonding = Some(UnbondingInfo {
+      amount: if at_risk {
+        account.dividends
+      } else {
+        total_amount
+      },
+      time: env.block.time,
+      at_risk: Some(at_risk),
+      delegation: Some(account.delegation),
+    });
+
+    let tranche = account.tranche();
+    let mut totals = pool.tranche(&tranche);
+    let prev_tranche_liquidity = totals.liquidity;
+
+    totals.liquidity -= account.liquidity;
+    totals.delegation -= account.delegation;
+
+    reweight_tranche(
+      &mut totals,
+      prev_tranche_liquidity,
+      account.liquidity,
+      account.multiplier(),
+      Uint128::zero(),
+      Uint128::zero(),
+    )?;
+
+    pool.set_tranche(&tranche, totals);
     pool.dividends -= account.dividends;
     pool.delegation -= account.delegation;
 
+    if at_risk {
+      let mut unbonding = pool.unbonding_totals(&tranche);
+      unbonding.liquidity += account.liquidity;
+      pool.set_unbonding_totals(&tranche, unbonding);
+      increment(deps.storage, &N_STAKE_ACCOUNTS_UNBONDING_AT_RISK, 1)?;
+    } else {
+      pool.liquidity -= account.liquidity;
+      account.liquidity = Uint128::zero();
+    }
+
     POOL.save(deps.storage, &pool)?;
 
-    account.liquidity = Uint128::zero();
     account.dividends = Uint128::zero();
     account.delegation = Uint128::zero();
+    account.lockup = None;
   }
 
   STAKE_ACCOUNTS.save(deps.storage, info.sender.clone(), &account)?;
 
+  snapshot_stake(deps.storage, env.block.height, &info.sender, &account)?;
+  snapshot_pool(deps.storage, env.block.height, &pool)?;
+
   // increment the delegation mutation counter, which lets the process method
   // know that a new LedgerEntry should be created when nexted executed, instead
   // of updating the existing latest entry.
@@ -52,10 +124,23 @@ pub fn unstake(
 
   increment(deps.storage, &N_STAKE_ACCOUNTS_UNBONDING, 1)?;
 
-  amortize(deps.storage, deps.api)?;
+  amortize(deps.storage, deps.api, env.block.time)?;
 
-  Ok(Response::new().add_attributes(vec![
-    attr("action", action),
-    attr("amount", total_amount.to_string()),
-  ]))
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("amount", total_amount.to_string()),
+      ])
+      .add_event(event.to_event())
+      .add_submessages(build_hook_submsgs(
+        deps.storage,
+        &HookPayload::StakingChanged {
+          staker: info.sender.clone(),
+          action: action.to_owned(),
+          delegation: account.delegation,
+          pool_delegation: pool.delegation,
+        },
+      )?),
+  )
 }
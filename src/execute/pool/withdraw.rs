@@ -1,12 +1,15 @@
 use crate::{
   error::{ContractError, ContractResult},
+  events::{ToEvent, WithdrawEvent},
+  models::PauseScope,
   state::{
-    amortize, load_stake_account, CONFIG, N_STAKE_ACCOUNTS, N_STAKE_ACCOUNTS_UNBONDING, POOL,
-    STAKE_ACCOUNTS,
+    amortize, ensure_not_paused, load_stake_account, snapshot_pool, snapshot_stake, sync_account,
+    CONFIG, N_DELEGATION_MUTATIONS, N_STAKE_ACCOUNTS, N_STAKE_ACCOUNTS_UNBONDING,
+    N_STAKE_ACCOUNTS_UNBONDING_AT_RISK, POOL, STAKE_ACCOUNTS,
   },
-  utils::decrement,
+  utils::{decrement, increment},
 };
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
 use cw_lib::utils::funds::build_send_submsg;
 
 pub fn withdraw(
@@ -14,23 +17,61 @@ pub fn withdraw(
   env: Env,
   info: MessageInfo,
 ) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Unstaking)?;
+
   let action = "withdraw";
-  let account = load_stake_account(deps.storage, &info.sender)?;
+  let mut account = load_stake_account(deps.storage, &info.sender)?;
   let token = POOL.load(deps.storage)?.token;
   let config = CONFIG.load(deps.storage)?;
+  let recipient = account.recipient(&info.sender);
 
   let mut resp = Response::new().add_attributes(vec![attr("action", action)]);
 
-  if let Some(unbonding) = account.unbonding {
+  if let Some(unbonding) = account.unbonding.clone() {
     // if the unbonding period has been met, remove the StakeAccount and create a submsg
     // for transferring the sender's tokens to the sender.
     if env.block.time.seconds() > unbonding.time.seconds() + config.unbonding_seconds.u64() {
+      let mut amount = unbonding.amount;
+
+      // apply the gains and losses shared by the account's unbonding liquidity
+      // and take it out of the pool.
+      if account.is_unbonding_at_risk() {
+        sync_account(deps.storage, deps.api, env.block.time, &mut account, true)?;
+
+        let mut pool = POOL.load(deps.storage)?;
+        let mut unbonding_totals = pool.unbonding_totals(&account.tranche());
+        unbonding_totals.liquidity -= account.liquidity;
+        pool.set_unbonding_totals(&account.tranche(), unbonding_totals);
+        pool.liquidity -= account.liquidity;
+        pool.dividends -= account.dividends;
+        POOL.save(deps.storage, &pool)?;
+        snapshot_pool(deps.storage, env.block.height, &pool)?;
+
+        amount += account.liquidity + account.dividends;
+
+        decrement(deps.storage, &N_STAKE_ACCOUNTS_UNBONDING_AT_RISK, 1)?;
+
+        // the account's liquidity is no longer part of the latest LedgerEntry
+        increment(deps.storage, &N_DELEGATION_MUTATIONS, Uint128::one())?;
+      }
+
       // remove the staking account
       STAKE_ACCOUNTS.remove(deps.storage, info.sender.clone());
+      account.delegation = Uint128::zero();
+      account.liquidity = Uint128::zero();
+      snapshot_stake(deps.storage, env.block.height, &info.sender, &account)?;
       decrement(deps.storage, &N_STAKE_ACCOUNTS, 1)?;
       resp = resp
-        .add_attribute("amount", unbonding.amount.to_string())
-        .add_submessage(build_send_submsg(&info.sender, unbonding.amount, &token)?);
+        .add_attribute("amount", amount.to_string())
+        .add_event(
+          WithdrawEvent {
+            staker: info.sender.clone(),
+            recipient: recipient.clone(),
+            amount,
+          }
+          .to_event(),
+        )
+        .add_submessage(build_send_submsg(&recipient, amount, &token)?);
     } else {
       // still unbonding
       return Err(ContractError::Unbonding);
@@ -42,7 +83,7 @@ pub fn withdraw(
 
   decrement(deps.storage, &N_STAKE_ACCOUNTS_UNBONDING, 1)?;
 
-  amortize(deps.storage, deps.api)?;
+  amortize(deps.storage, deps.api, env.block.time)?;
 
   Ok(resp)
 }
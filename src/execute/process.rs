@@ -2,17 +2,27 @@ use std::collections::HashMap;
 
 use crate::{
   error::{ContractError, ContractResult},
-  models::{AccountTokenAmount, Client, Config, HouseEvent, Pool, RateLimitConfig, Usage},
-  msg::Job,
+  events::{
+    ClientEvent, ClientEventKind, JackpotEvent, JackpotEventKind, ProcessEvent,
+    RateLimitTriggeredEvent, ToEvent,
+  },
+  models::{
+    AccountTokenAmount, Client, Config, HouseEvent, PauseScope, Pool, RateLimitConfig, Usage,
+  },
+  msg::{HookPayload, Job},
   state::{
-    amortize, ensure_client_not_rate_limited, ensure_has_funds, ensure_min_amount, load_client,
-    suspend_client, upsert_ledger_entry, validate_address, CLIENTS, CLIENT_EXECUTION_COUNTS,
-    CONFIG, EVENTS, MAX_EVENT_QUEUE_SIZE, POOL, USAGE,
+    amortize, build_hook_submsgs, contribute_to_jackpots, credit_referrer, enforce_player_limits,
+    ensure_client_not_rate_limited, ensure_has_funds, ensure_initiator_allowed, ensure_min_amount,
+    ensure_not_paused, load_client, log_event, record_client_stats, record_player_stats,
+    snapshot_pool, suspend_client, upsert_ledger_entry, validate_address, CLIENTS,
+    CLIENT_EXECUTION_COUNTS, CONFIG, JACKPOTS, JACKPOT_CONTRIBUTIONS, JACKPOT_PAYOUTS, POOL,
+    RAKEBACK, RAKEBACK_TOTAL, USAGE,
   },
   utils::mul_pct,
 };
 use cosmwasm_std::{
-  attr, Addr, Api, BlockInfo, DepsMut, Env, MessageInfo, Response, Storage, Uint128, Uint64,
+  attr, Addr, Api, BlockInfo, DepsMut, Env, Event, MessageInfo, Response, Storage, SubMsg,
+  Timestamp, Uint128, Uint64,
 };
 use cw_lib::{
   models::Token,
@@ -24,21 +34,42 @@ enum RateLimitEvent {
   Triggered,
 }
 
+/// Result of processing a single Job.
+#[derive(Default)]
+struct JobOutcome {
+  is_rate_limited: bool,
+  revenue: Uint128,
+  tax: Uint128,
+  rakeback: Uint128,
+  referral: Uint128,
+  jackpot_contribution: Uint128,
+  revenue_share: Uint128,
+  delta_revenue: Uint128,
+  delta_dividends: Uint128,
+  loss: Uint128,
+  suspension_reason: Option<&'static str>,
+  events: Vec<Event>,
+}
+
 pub fn process_many(
   deps: DepsMut,
   env: Env,
   info: MessageInfo,
   jobs: Vec<Job>,
 ) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Process)?;
+
   let config = CONFIG.load(deps.storage)?;
   let mut pool = POOL.load(deps.storage)?;
   let mut client = load_client(deps.storage, &info.sender)?;
   let mut incoming_totals: HashMap<Addr, Uint128> = HashMap::with_capacity(jobs.len());
   let mut outgoing_totals: HashMap<Addr, Uint128> = HashMap::with_capacity(jobs.len());
   let mut resp = Response::new().add_attributes(vec![attr("action", "process_many")]);
+  let mut hook_submsgs: Vec<SubMsg> = vec![];
+  let mut total_tax = Uint128::zero();
 
   for job in jobs.iter() {
-    let is_rate_limited = process(
+    let outcome = process(
       deps.api,
       deps.storage,
       &env,
@@ -50,10 +81,54 @@ pub fn process_many(
       job.initiator.clone(),
       job.incoming.clone(),
       job.outgoing.clone(),
+      job.jackpot.clone(),
     )?;
 
+    resp = resp.add_events(outcome.events).add_event(
+      ProcessEvent {
+        client: info.sender.clone(),
+        initiator: job.initiator.clone(),
+        incoming: job.incoming.as_ref().map(|x| x.amount).unwrap_or_default(),
+        outgoing: job.outgoing.as_ref().map(|x| x.amount).unwrap_or_default(),
+        revenue: outcome.revenue,
+        tax: outcome.tax,
+        rakeback: outcome.rakeback,
+        referral: outcome.referral,
+        jackpot_contribution: outcome.jackpot_contribution,
+        revenue_share: outcome.revenue_share,
+        delta_revenue: outcome.delta_revenue,
+        delta_dividends: outcome.delta_dividends,
+        loss: outcome.loss,
+        rate_limited: outcome.is_rate_limited,
+      }
+      .to_event(),
+    );
+
+    total_tax += outcome.tax;
+
+    if !outcome.loss.is_zero() {
+      hook_submsgs.extend(build_hook_submsgs(
+        deps.storage,
+        &HookPayload::LargeLoss {
+          client: info.sender.clone(),
+          initiator: job.initiator.clone(),
+          amount: outcome.loss,
+        },
+      )?);
+    }
+
+    if let Some(reason) = outcome.suspension_reason {
+      hook_submsgs.extend(build_hook_submsgs(
+        deps.storage,
+        &HookPayload::ClientSuspended {
+          client: info.sender.clone(),
+          reason: reason.to_owned(),
+        },
+      )?);
+    }
+
     // Send refund and continue if rate limited
-    if is_rate_limited {
+    if outcome.is_rate_limited {
       if let Some(incoming) = &job.incoming {
         if !incoming.amount.is_zero() {
           resp = resp
@@ -102,6 +177,9 @@ pub fn process_many(
     },
   )?;
 
+  // Record the pool's stake totals after processing all jobs
+  snapshot_pool(deps.storage, env.block.height, &pool)?;
+
   let mut total_incoming_amount = Uint128::zero();
   let mut total_outgoing_amount = Uint128::zero();
 
@@ -131,7 +209,17 @@ pub fn process_many(
     resp = resp.add_submessage(build_send_submsg(to_addr, *amount, &pool.token)?)
   }
 
-  Ok(resp.add_attributes(vec![
+  if !total_tax.is_zero() {
+    hook_submsgs.extend(build_hook_submsgs(
+      deps.storage,
+      &HookPayload::TaxesAccrued {
+        amount: total_tax,
+        total: pool.taxes,
+      },
+    )?);
+  }
+
+  Ok(resp.add_submessages(hook_submsgs).add_attributes(vec![
     attr("jobs", jobs.len().to_string()),
     attr("outgoing", total_outgoing_amount.to_string()),
     attr("incoming", total_incoming_amount.to_string()),
@@ -145,6 +233,7 @@ pub fn process_one(
   initiator: Addr,
   maybe_incoming: Option<AccountTokenAmount>,
   maybe_outgoing: Option<AccountTokenAmount>,
+  maybe_jackpot: Option<String>,
 ) -> ContractResult<Response> {
   Ok(process_many(
     deps,
@@ -154,6 +243,7 @@ pub fn process_one(
       initiator,
       incoming: maybe_incoming,
       outgoing: maybe_outgoing,
+      jackpot: maybe_jackpot,
     }],
   )?)
 }
@@ -170,14 +260,33 @@ fn process(
   initiator: Addr,
   maybe_incoming: Option<AccountTokenAmount>,
   maybe_outgoing: Option<AccountTokenAmount>,
-) -> ContractResult<bool> {
+  maybe_jackpot: Option<String>,
+) -> ContractResult<JobOutcome> {
   validate_address(api, &initiator)?;
   ensure_client_not_rate_limited(client)?;
 
+  // A job can only name a jackpot to pay a win out of it.
+  if maybe_jackpot.is_some() {
+    let incoming_amount = maybe_incoming
+      .as_ref()
+      .map(|x| x.amount)
+      .unwrap_or_default();
+    let outgoing_amount = maybe_outgoing
+      .as_ref()
+      .map(|x| x.amount)
+      .unwrap_or_default();
+    if outgoing_amount <= incoming_amount {
+      return Err(ContractError::JackpotWithoutPayout);
+    }
+  }
+
+  let mut outcome = JobOutcome::default();
+
   // Abort if nothings being sent or received
   if maybe_incoming.is_none() && maybe_outgoing.is_none() {
-    amortize(storage, api)?;
-    return Ok(false);
+    amortize(storage, api, env.block.time)?;
+    *pool = POOL.load(storage)?;
+    return Ok(outcome);
   }
 
   // Check if outgoing amount exceeds budget. If not, disconnect the client if
@@ -204,6 +313,8 @@ fn process(
 
   incoming.validate(api)?;
 
+  ensure_initiator_allowed(storage, env.block.time, client_address, client, &initiator)?;
+
   let mut is_rate_limit_triggered = false;
 
   // Apply rate limiting if no budget is set for the client.
@@ -224,15 +335,40 @@ fn process(
         RateLimitEvent::Triggered => {
           is_rate_limit_triggered = true;
           client.is_suspended = true;
+          outcome.suspension_reason = Some("rate_limit");
           CLIENTS.save(storage, client_address.clone(), &client)?;
-          EVENTS.push_front(
+          outcome.events.push(
+            RateLimitTriggeredEvent {
+              client: client_address.clone(),
+              initiator: initiator.clone(),
+              target: client_address.clone(),
+            }
+            .to_event(),
+          );
+          outcome.events.push(
+            ClientEvent::new(
+              ClientEventKind::Suspended,
+              client_address,
+              Some("rate_limit"),
+            )
+            .to_event(),
+          );
+          log_event(
             storage,
-            &HouseEvent::ClientRateLimitTriggered {
+            HouseEvent::ClientRateLimitTriggered {
               client: client_address.clone(),
               initiator: initiator.clone(),
               block: env.block.clone(),
             },
           )?;
+          log_event(
+            storage,
+            HouseEvent::ClientSuspended {
+              block: env.block.clone(),
+              client: client_address.clone(),
+              reason: "rate_limit".to_owned(),
+            },
+          )?;
         },
       }
     }
@@ -252,9 +388,17 @@ fn process(
           RateLimitEvent::Throttled => return Err(ContractError::AccountSuspended),
           RateLimitEvent::Triggered => {
             is_rate_limit_triggered = true;
-            EVENTS.push_front(
+            outcome.events.push(
+              RateLimitTriggeredEvent {
+                client: client_address.clone(),
+                initiator: initiator.clone(),
+                target: initiator.clone(),
+              }
+              .to_event(),
+            );
+            log_event(
               storage,
-              &HouseEvent::AccountRateLimitTriggered {
+              HouseEvent::AccountRateLimitTriggered {
                 client: client_address.clone(),
                 initiator: initiator.clone(),
                 block: env.block.clone(),
@@ -266,15 +410,24 @@ fn process(
     }
   }
 
-  // Ensure that the events buffer is capped at max size
-  while EVENTS.len(storage)? > MAX_EVENT_QUEUE_SIZE {
-    EVENTS.pop_back(storage)?;
-  }
-
   if is_rate_limit_triggered {
-    return Ok(true);
+    outcome.is_rate_limited = true;
+    return Ok(outcome);
   }
 
+  let outgoing_amount = maybe_outgoing
+    .as_ref()
+    .map(|x| x.amount)
+    .unwrap_or_default();
+
+  enforce_player_limits(
+    storage,
+    &env.block,
+    &initiator,
+    incoming.amount,
+    outgoing_amount,
+  )?;
+
   // Take earnings and/or send payment
   if let Some(outgoing) = maybe_outgoing {
     outgoing.validate(api)?;
@@ -282,24 +435,117 @@ fn process(
       if outgoing.amount > incoming.amount {
         // Pay out of house to the outgoing account.
         let payment = outgoing.amount - incoming.amount;
-        send(api, storage, info, pool, client, payment)?;
+        if let Some(name) = &maybe_jackpot {
+          send_from_jackpot(storage, info, client, name, payment)?;
+          outcome.events.push(
+            JackpotEvent {
+              kind: JackpotEventKind::PaidOut,
+              name: name.clone(),
+              amount: payment,
+              recipient: Some(outgoing.address.clone()),
+            }
+            .to_event(),
+          );
+        } else {
+          send(api, storage, env.block.time, info, pool, client, payment)?;
+        }
+        outcome.loss = payment;
+        if let Some(threshold) = config.large_payout_threshold {
+          if payment >= threshold {
+            log_event(
+              storage,
+              HouseEvent::LargePayout {
+                block: env.block.clone(),
+                client: client_address.clone(),
+                initiator: initiator.clone(),
+                amount: payment,
+              },
+            )?;
+          }
+        }
       } else {
         // Take payment from incoming account.
         let revenue = incoming.amount - outgoing.amount;
-        receive(api, storage, info, pool, client, revenue, &config)?;
+        receive(
+          api,
+          storage,
+          env.block.time,
+          info,
+          pool,
+          client,
+          &initiator,
+          revenue,
+          &config,
+          &mut outcome,
+        )?;
       };
     }
   } else if !incoming.amount.is_zero() {
     // There's only incoming, no outgoing, so the house takes revenue.
-    receive(api, storage, info, pool, client, incoming.amount, &config)?;
+    receive(
+      api,
+      storage,
+      env.block.time,
+      info,
+      pool,
+      client,
+      &initiator,
+      incoming.amount,
+      &config,
+      &mut outcome,
+    )?;
   }
 
+  record_client_stats(
+    storage,
+    &env.block,
+    config,
+    client_address,
+    &initiator,
+    outcome.revenue,
+    outcome.loss,
+  )?;
+
+  record_player_stats(
+    storage,
+    &env.block,
+    config,
+    client_address,
+    &initiator,
+    incoming.amount,
+    outgoing_amount,
+  )?;
+
   // Suspend client when it has used all of its remaining budget
   if suspend_after_use {
     suspend_client(storage, client_address)?;
+    outcome.suspension_reason = Some("budget_exhausted");
+    log_event(
+      storage,
+      HouseEvent::BudgetExhausted {
+        block: env.block.clone(),
+        client: client_address.clone(),
+      },
+    )?;
+    log_event(
+      storage,
+      HouseEvent::ClientSuspended {
+        block: env.block.clone(),
+        client: client_address.clone(),
+        reason: "budget_exhausted".to_owned(),
+      },
+    )?;
+    outcome.events.push(
+      ClientEvent::new(
+        ClientEventKind::Suspended,
+        client_address,
+        Some("budget_exhausted"),
+      )
+      .to_event(),
+    );
   }
 
-  Ok(false)
+  Ok(outcome)
 }
 
 fn throttle(
@@ -380,6 +626,7 @@ fn throttle(
 fn send(
   api: &dyn Api,
   storage: &mut dyn Storage,
+  time: Timestamp,
   info: &MessageInfo,
   pool: &mut Pool,
   client: &mut Client,
@@ -387,7 +634,7 @@ fn send(
 ) -> ContractResult<()> {
   ensure_min_amount(payment, Uint128::one())?;
 
-  upsert_ledger_entry(storage, &pool, Uint128::zero(), Uint128::zero(), payment)?;
+  upsert_ledger_entry(storage, pool, Uint128::zero(), Uint128::zero(), payment)?;
 
   // Increment client's total expenditure, subtracting from pool's liquidity.
   client.expense += payment;
@@ -396,7 +643,62 @@ fn send(
   POOL.save(storage, &pool)?;
   CLIENTS.save(storage, info.sender.clone(), &client)?;
 
-  amortize(storage, api)?;
+  // amortizing can release lapsed lockups, which reweights the pool's tranches
+  amortize(storage, api, time)?;
+  *pool = POOL.load(storage)?;
+
+  Ok(())
+}
+
+/// Pay a jackpot win out of the named jackpot's balance rather than the pool's
+/// liquidity. A client can only pay out of its own jackpots or out of global
+/// ones, which every client feeds. Out of a global jackpot, a client can only
+/// pay up to what it has contributed, net of what it has already paid out, so
+/// that one client can't drain what the others funded.
+fn send_from_jackpot(
+  storage: &mut dyn Storage,
+  info: &MessageInfo,
+  client: &mut Client,
+  name: &String,
+  payment: Uint128,
+) -> ContractResult<()> {
+  ensure_min_amount(payment, Uint128::one())?;
+
+  let mut jackpot = JACKPOTS
+    .may_load(storage, name.clone())?
+    .ok_or(ContractError::JackpotNotFound)?;
+
+  let key = (name.clone(), info.sender.clone());
+  let paid_out = JACKPOT_PAYOUTS
+    .may_load(storage, key.clone())?
+    .unwrap_or_default()
+    + payment;
+
+  if let Some(jackpot_client) = &jackpot.client {
+    if *jackpot_client != info.sender {
+      return Err(ContractError::JackpotClientMismatch);
+    }
+  } else {
+    let contributed = JACKPOT_CONTRIBUTIONS
+      .may_load(storage, key.clone())?
+      .unwrap_or_default();
+    if paid_out > contributed {
+      return Err(ContractError::JackpotPayoutExceedsContributions);
+    }
+  }
+  if payment > jackpot.balance {
+    return Err(ContractError::InsufficientJackpotBalance);
+  }
+
+  jackpot.balance -= payment;
+  jackpot.paid_out += payment;
+
+  JACKPOTS.save(storage, name.clone(), &jackpot)?;
+  JACKPOT_PAYOUTS.save(storage, key, &paid_out)?;
+
+  client.expense += payment;
+
+  CLIENTS.save(storage, info.sender.clone(), &client)?;
 
   Ok(())
 }
@@ -404,22 +706,67 @@ fn send(
 fn receive(
   api: &dyn Api,
   storage: &mut dyn Storage,
+  time: Timestamp,
   info: &MessageInfo,
   pool: &mut Pool,
   client: &mut Client,
+  initiator: &Addr,
   revenue: Uint128,
   config: &Config,
+  outcome: &mut JobOutcome,
 ) -> ContractResult<()> {
   ensure_min_amount(revenue, Uint128::one())?;
 
-  let tax = mul_pct(revenue, config.tax_rate);
-  let revenue_post_tax = revenue - tax;
-  let delta_revenue = mul_pct(revenue_post_tax, config.restake_rate.into());
-  let delta_dividends = revenue_post_tax - delta_revenue;
+  // Split off the referrer's share before tax and restaking.
+  let referral = credit_referrer(storage, config, initiator, revenue)?;
+
+  // Accrue the client operator's share, also before tax and restaking.
+  let client_share =
+    mul_pct(revenue, client.config.revenue_share_pct.unwrap_or_default()).min(revenue - referral);
+  if !client_share.is_zero() {
+    client.revenue_share_balance =
+      Some(client.revenue_share_balance.unwrap_or_default() + client_share);
+  }
+
+  // Feed jackpot sub-pools, which are paid out separately from liquidity.
+  let jackpot_contribution = contribute_to_jackpots(
+    storage,
+    &info.sender,
+    revenue,
+    revenue - referral - client_share,
+  )?;
+
+  let revenue_post_shares = revenue - referral - client_share - jackpot_contribution;
+
+  let tax = mul_pct(revenue_post_shares, config.tax_rate);
+  let revenue_post_tax = revenue_post_shares - tax;
+
+  // Return a share of the initiator's net loss to them as rakeback, taken out
+  // of what would otherwise go to the pool.
+  let rakeback_rate = client
+    .config
+    .rakeback_rate
+    .or(config.rakeback_rate)
+    .unwrap_or_default();
+  let rakeback = mul_pct(revenue, rakeback_rate).min(revenue_post_tax);
+  if !rakeback.is_zero() {
+    RAKEBACK.update(
+      storage,
+      initiator.clone(),
+      |maybe_amount| -> ContractResult<_> { Ok(maybe_amount.unwrap_or_default() + rakeback) },
+    )?;
+    RAKEBACK_TOTAL.update(storage, |total| -> ContractResult<_> {
+      Ok(total + rakeback)
+    })?;
+  }
+
+  let revenue_post_rakeback = revenue_post_tax - rakeback;
+  let delta_revenue = mul_pct(revenue_post_rakeback, config.restake_rate.into());
+  let delta_dividends = revenue_post_rakeback - delta_revenue;
 
   upsert_ledger_entry(
     storage,
-    &pool,
+    pool,
     delta_revenue,
     delta_dividends,
     Uint128::zero(),
@@ -435,7 +782,18 @@ fn receive(
   POOL.save(storage, &pool)?;
   CLIENTS.save(storage, info.sender.clone(), &client)?;
 
-  amortize(storage, api)?;
+  // amortizing can release lapsed lockups, which reweights the pool's tranches
+  amortize(storage, api, time)?;
+  *pool = POOL.load(storage)?;
+
+  outcome.revenue = revenue;
+  outcome.tax = tax;
+  outcome.rakeback = rakeback;
+  outcome.referral = referral;
+  outcome.jackpot_contribution = jackpot_contribution;
+  outcome.revenue_share = client_share;
+  outcome.delta_revenue = delta_revenue;
+  outcome.delta_dividends = delta_dividends;
 
   Ok(())
 }
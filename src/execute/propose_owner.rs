@@ -0,0 +1,52 @@
+use crate::{
+  error::ContractResult,
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  models::TimelockAction,
+  state::{enqueue_change, ensure_sender_is_allowed, validate_owner, PENDING_OWNER},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+use cw_lib::models::Owner;
+
+/// Nominate a new owner. Ownership is only transferred once the nominee calls
+/// AcceptOwnership.
+pub fn propose_owner(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  owner: Owner,
+) -> ContractResult<Response> {
+  let action = "propose_owner";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/set-owner")?;
+
+  validate_owner(deps.api, &owner)?;
+
+  let resp = Response::new().add_attributes(vec![attr("action", action)]);
+
+  if let Some(change) = enqueue_change(
+    deps.storage,
+    &env,
+    &info.sender,
+    TimelockAction::ProposeOwner(owner.clone()),
+  )? {
+    return Ok(
+      resp
+        .add_attributes(vec![
+          attr("pending_change_id", change.id.to_string()),
+          attr("eta", change.eta.seconds().to_string()),
+        ])
+        .add_event(
+          AdminEvent {
+            kind: AdminEventKind::ChangeQueued,
+            sender: info.sender.clone(),
+            pending_change_id: Some(change.id),
+          }
+          .to_event(),
+        ),
+    );
+  }
+
+  PENDING_OWNER.save(deps.storage, &owner)?;
+
+  Ok(resp.add_event(AdminEvent::new(AdminEventKind::OwnerProposed, &info.sender).to_event()))
+}
@@ -1,9 +1,12 @@
 use crate::{
   error::{ContractError, ContractResult},
-  models::RevenueStream,
+  events::{ReceiveEvent, ToEvent},
+  models::{PauseScope, RevenueStream},
+  msg::HookPayload,
   state::{
-    amortize, ensure_has_funds, ensure_min_amount, upsert_ledger_entry, validate_address, CONFIG,
-    POOL, STREAMS, TOTAL_STREAM_REVENUE,
+    amortize, build_hook_submsgs, ensure_has_funds, ensure_min_amount, ensure_not_paused,
+    snapshot_pool, upsert_ledger_entry, validate_address, CONFIG, POOL, STREAMS,
+    TOTAL_STREAM_REVENUE,
   },
   utils::{increment, mul_pct},
 };
@@ -16,6 +19,8 @@ pub fn receive(
   info: MessageInfo,
   revenue: Uint128,
 ) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Revenue)?;
+
   validate_address(deps.api, &info.sender)?;
   ensure_min_amount(revenue, Uint128::one())?;
 
@@ -68,7 +73,7 @@ pub fn receive(
 
   upsert_ledger_entry(
     deps.storage,
-    &pool,
+    &mut pool,
     delta_revenue,
     delta_dividends,
     Uint128::zero(),
@@ -80,8 +85,30 @@ pub fn receive(
   pool.taxes += tax;
 
   POOL.save(deps.storage, &pool)?;
+  snapshot_pool(deps.storage, env.block.height, &pool)?;
+
+  amortize(deps.storage, deps.api, env.block.time)?;
 
-  amortize(deps.storage, deps.api)?;
+  if !tax.is_zero() {
+    resp = resp.add_submessages(build_hook_submsgs(
+      deps.storage,
+      &HookPayload::TaxesAccrued {
+        amount: tax,
+        total: pool.taxes,
+      },
+    )?);
+  }
 
-  Ok(resp)
+  Ok(
+    resp.add_event(
+      ReceiveEvent {
+        source: info.sender.clone(),
+        revenue,
+        tax,
+        delta_revenue,
+        delta_dividends,
+      }
+      .to_event(),
+    ),
+  )
 }
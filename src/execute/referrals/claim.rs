@@ -0,0 +1,54 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{ReferralEvent, ReferralEventKind, ToEvent},
+  models::PauseScope,
+  state::{ensure_not_paused, POOL, REFERRAL_ACCOUNTS},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_lib::utils::funds::build_send_submsg;
+
+/// Send the sender's unclaimed referral earnings to them.
+pub fn claim(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+) -> ContractResult<Response> {
+  ensure_not_paused(deps.storage, PauseScope::Claims)?;
+
+  let action = "claim_referral_earnings";
+
+  let mut account = REFERRAL_ACCOUNTS
+    .may_load(deps.storage, info.sender.clone())?
+    .ok_or(ContractError::NothingToClaim)?;
+
+  let amount = account.balance;
+
+  if amount.is_zero() {
+    return Err(ContractError::NothingToClaim);
+  }
+
+  account.claimed += amount;
+  account.balance = Uint128::zero();
+
+  REFERRAL_ACCOUNTS.save(deps.storage, info.sender.clone(), &account)?;
+
+  let pool = POOL.load(deps.storage)?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("amount", amount.to_string()),
+      ])
+      .add_event(
+        ReferralEvent {
+          kind: ReferralEventKind::EarningsClaimed,
+          referrer: info.sender.clone(),
+          player: None,
+          amount,
+        }
+        .to_event(),
+      )
+      .add_submessage(build_send_submsg(&info.sender, amount, &pool.token)?),
+  )
+}
@@ -0,0 +1,5 @@
+mod claim;
+mod set_referrer;
+
+pub use claim::claim;
+pub use set_referrer::set_referrer;
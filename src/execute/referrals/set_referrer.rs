@@ -0,0 +1,63 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{ReferralEvent, ReferralEventKind, ToEvent},
+  models::ReferralAccount,
+  state::{ensure_sender_is_allowed, validate_address, REFERRALS, REFERRAL_ACCOUNTS, REFERRERS},
+};
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+
+/// Register the sender as a player referred by the given affiliate. A player's
+/// referrer can only be set once, and only to an affiliate approved by the
+/// owner, so players can't refer themselves through another wallet.
+pub fn set_referrer(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  referrer: Addr,
+) -> ContractResult<Response> {
+  let action = "set_referrer";
+
+  validate_address(deps.api, &referrer)?;
+
+  if referrer == info.sender {
+    return Err(ContractError::InvalidReferrer);
+  }
+  if REFERRERS.has(deps.storage, info.sender.clone()) {
+    return Err(ContractError::ReferrerAlreadySet);
+  }
+  ensure_sender_is_allowed(&deps.as_ref(), &referrer, "/house/referrer")
+    .map_err(|_| ContractError::ReferrerNotApproved)?;
+
+  REFERRERS.save(deps.storage, info.sender.clone(), &referrer)?;
+  REFERRALS.save(
+    deps.storage,
+    (referrer.clone(), info.sender.clone()),
+    &env.block.time,
+  )?;
+  REFERRAL_ACCOUNTS.update(
+    deps.storage,
+    referrer.clone(),
+    |maybe_account| -> ContractResult<_> {
+      let mut account = maybe_account.unwrap_or_else(|| ReferralAccount::new());
+      account.n_referrals += 1;
+      Ok(account)
+    },
+  )?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("referrer", referrer.to_string()),
+      ])
+      .add_event(
+        ReferralEvent {
+          kind: ReferralEventKind::ReferrerSet,
+          referrer,
+          player: Some(info.sender.clone()),
+          amount: Uint128::zero(),
+        }
+        .to_event(),
+      ),
+  )
+}
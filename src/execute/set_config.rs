@@ -1,21 +1,76 @@
 use crate::{
   error::ContractResult,
-  models::Config,
-  state::{ensure_sender_is_allowed, CONFIG},
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  models::{HouseEvent, TimelockAction},
+  msg::{ConfigUpdates, OptionalUpdate},
+  state::{
+    enqueue_change, ensure_sender_is_allowed, log_event, update_config, validate_address, CONFIG,
+  },
 };
 use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
 
 pub fn set_config(
   deps: DepsMut,
-  _env: Env,
+  env: Env,
   info: MessageInfo,
-  config: Config,
+  updates: ConfigUpdates,
 ) -> ContractResult<Response> {
   let action = "set_config";
 
   ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/set-config")?;
 
-  CONFIG.save(deps.storage, &config)?;
+  if let Some(OptionalUpdate::Set(acl)) = &updates.staker_acl {
+    validate_address(deps.api, acl)?;
+  }
 
-  Ok(Response::new().add_attributes(vec![attr("action", action)]))
+  // validate the resulting config up front so that an invalid change is never
+  // queued behind the timelock.
+  let mut config = CONFIG.load(deps.storage)?;
+  config.apply_updates(&updates);
+  config.validate()?;
+
+  let resp = Response::new().add_attributes(vec![attr("action", action)]);
+
+  if let Some(change) = enqueue_change(
+    deps.storage,
+    &env,
+    &info.sender,
+    TimelockAction::SetConfig(updates.clone()),
+  )? {
+    return Ok(
+      resp
+        .add_attributes(vec![
+          attr("pending_change_id", change.id.to_string()),
+          attr("eta", change.eta.seconds().to_string()),
+        ])
+        .add_event(
+          AdminEvent {
+            kind: AdminEventKind::ChangeQueued,
+            sender: info.sender.clone(),
+            pending_change_id: Some(change.id),
+          }
+          .to_event(),
+        ),
+    );
+  }
+
+  let change_attrs = update_config(deps.storage, &updates)?;
+
+  log_event(
+    deps.storage,
+    HouseEvent::ConfigChanged {
+      block: env.block.clone(),
+      sender: info.sender.clone(),
+    },
+  )?;
+
+  // the event carries each changed field's before and after value, so that
+  // indexers can rebuild the config's history.
+  Ok(
+    resp.add_attributes(change_attrs.clone()).add_event(
+      AdminEvent::new(AdminEventKind::ConfigUpdated, &info.sender)
+        .to_event()
+        .add_attributes(change_attrs),
+    ),
+  )
 }
@@ -1,23 +1,51 @@
 use crate::{
   error::ContractResult,
-  models::TaxRecipient,
-  state::{ensure_sender_is_allowed, insert_tax_recipients, TAX_RECIPIENTS},
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  models::{TaxRecipient, TimelockAction},
+  state::{
+    enqueue_change, ensure_sender_is_allowed, replace_tax_recipients, validate_tax_recipients,
+  },
 };
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
 
 pub fn set_taxes(
   deps: DepsMut,
-  _env: Env,
+  env: Env,
   info: MessageInfo,
   recipients: Vec<TaxRecipient>,
 ) -> ContractResult<Response> {
-  let action = "/house/set-taxes";
+  let action = "set_taxes";
 
-  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, action)?;
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/set-taxes")?;
 
-  TAX_RECIPIENTS.clear(deps.storage);
+  validate_tax_recipients(deps.api, &recipients)?;
 
-  insert_tax_recipients(deps.storage, &recipients)?;
+  let resp = Response::new().add_attribute("action", action);
 
-  Ok(Response::new().add_attribute("action", action))
+  if let Some(change) = enqueue_change(
+    deps.storage,
+    &env,
+    &info.sender,
+    TimelockAction::SetTaxes(recipients.clone()),
+  )? {
+    return Ok(
+      resp
+        .add_attributes(vec![
+          attr("pending_change_id", change.id.to_string()),
+          attr("eta", change.eta.seconds().to_string()),
+        ])
+        .add_event(
+          AdminEvent {
+            kind: AdminEventKind::ChangeQueued,
+            sender: info.sender.clone(),
+            pending_change_id: Some(change.id),
+          }
+          .to_event(),
+        ),
+    );
+  }
+
+  replace_tax_recipients(deps.storage, deps.api, &recipients)?;
+
+  Ok(resp.add_event(AdminEvent::new(AdminEventKind::TaxesUpdated, &info.sender).to_event()))
 }
@@ -0,0 +1,39 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  state::{ensure_sender_is_allowed, PENDING_CHANGES},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint64};
+
+pub fn cancel(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  id: Uint64,
+) -> ContractResult<Response> {
+  let action = "cancel_pending_change";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/timelock/cancel")?;
+
+  if !PENDING_CHANGES.has(deps.storage, id.u64()) {
+    return Err(ContractError::PendingChangeNotFound);
+  }
+
+  PENDING_CHANGES.remove(deps.storage, id.u64());
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("pending_change_id", id.to_string()),
+      ])
+      .add_event(
+        AdminEvent {
+          kind: AdminEventKind::ChangeCancelled,
+          sender: info.sender.clone(),
+          pending_change_id: Some(id),
+        }
+        .to_event(),
+      ),
+  )
+}
@@ -0,0 +1,70 @@
+use crate::{
+  error::{ContractError, ContractResult},
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  models::{HouseEvent, TimelockAction},
+  state::{
+    log_event, replace_tax_recipients, update_config, validate_owner, PENDING_CHANGES,
+    PENDING_OWNER,
+  },
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint64};
+
+/// Apply a queued change once its delay has elapsed. Anyone may execute it.
+pub fn execute(
+  deps: DepsMut,
+  env: Env,
+  info: MessageInfo,
+  id: Uint64,
+) -> ContractResult<Response> {
+  let action = "execute_pending_change";
+  let change = PENDING_CHANGES
+    .may_load(deps.storage, id.u64())?
+    .ok_or(ContractError::PendingChangeNotFound)?;
+
+  if !change.is_ready(env.block.time) {
+    return Err(ContractError::TimelockNotExpired);
+  }
+
+  PENDING_CHANGES.remove(deps.storage, id.u64());
+
+  let mut resp = Response::new()
+    .add_attributes(vec![
+      attr("action", action),
+      attr("pending_change_id", id.to_string()),
+    ])
+    .add_event(
+      AdminEvent {
+        kind: AdminEventKind::ChangeExecuted,
+        sender: info.sender.clone(),
+        pending_change_id: Some(id),
+      }
+      .to_event(),
+    );
+
+  match &change.action {
+    TimelockAction::SetConfig(updates) => {
+      let change_attrs = update_config(deps.storage, updates)?;
+      resp = resp.add_attributes(change_attrs.clone()).add_event(
+        AdminEvent::new(AdminEventKind::ConfigUpdated, &change.proposer)
+          .to_event()
+          .add_attributes(change_attrs),
+      );
+      log_event(
+        deps.storage,
+        HouseEvent::ConfigChanged {
+          block: env.block.clone(),
+          sender: change.proposer.clone(),
+        },
+      )?;
+    },
+    TimelockAction::SetTaxes(recipients) => {
+      replace_tax_recipients(deps.storage, deps.api, recipients)?;
+    },
+    TimelockAction::ProposeOwner(owner) => {
+      validate_owner(deps.api, owner)?;
+      PENDING_OWNER.save(deps.storage, owner)?;
+    },
+  }
+
+  Ok(resp)
+}
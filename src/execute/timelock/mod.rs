@@ -0,0 +1,5 @@
+mod cancel;
+mod execute;
+
+pub use cancel::cancel;
+pub use execute::execute;
@@ -0,0 +1,39 @@
+use crate::{
+  error::ContractResult,
+  events::{AdminEvent, AdminEventKind, ToEvent},
+  models::PauseScope,
+  state::{ensure_sender_is_allowed, PAUSED_SCOPES},
+};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+/// Unpause the given scopes or, if none are given, every scope.
+pub fn unpause(
+  deps: DepsMut,
+  _env: Env,
+  info: MessageInfo,
+  maybe_scopes: Option<Vec<PauseScope>>,
+) -> ContractResult<Response> {
+  let action = "unpause";
+
+  ensure_sender_is_allowed(&deps.as_ref(), &info.sender, "/house/unpause")?;
+
+  let paused_scopes = PAUSED_SCOPES.update(deps.storage, |paused| -> ContractResult<_> {
+    Ok(if let Some(scopes) = &maybe_scopes {
+      paused
+        .into_iter()
+        .filter(|scope| !scopes.contains(scope))
+        .collect()
+    } else {
+      vec![]
+    })
+  })?;
+
+  Ok(
+    Response::new()
+      .add_attributes(vec![
+        attr("action", action),
+        attr("n_paused_scopes", paused_scopes.len().to_string()),
+      ])
+      .add_event(AdminEvent::new(AdminEventKind::Unpaused, &info.sender).to_event()),
+  )
+}
@@ -13,8 +13,15 @@ pub mod query;
 #[cfg(not(feature = "library"))]
 pub mod migrations;
 
+#[cfg(not(feature = "library"))]
+pub mod reply;
+
 pub mod error;
+pub mod events;
 pub mod models;
 pub mod msg;
 pub mod state;
 pub mod utils;
+
+#[cfg(all(test, not(feature = "library")))]
+mod tests;
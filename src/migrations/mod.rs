@@ -1,2 +1,3 @@
 pub mod v0_0_4;
 pub mod v0_0_5;
+pub mod v0_0_6;
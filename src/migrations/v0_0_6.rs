@@ -0,0 +1,71 @@
+use crate::{
+  error::ContractResult,
+  models::{HouseEventRecord, StakeAccount, TrancheTotals},
+  state::{
+    snapshot_pool, snapshot_stake, EVENTS, EVENT_LOG, EVENT_LOG_SIZE, EVENT_SEQ_NO,
+    MEMOIZATION_QUEUE, MEMOIZATION_QUEUE_MEMBERS, N_STAKE_ACCOUNTS_UNBONDING_AT_RISK,
+    PAUSED_SCOPES, PENDING_CHANGE_SEQ_NO, POOL, PROPOSAL_SEQ_NO, RAKEBACK_TOTAL,
+    REWARD_STREAM_SEQ_NO, STAKE_ACCOUNTS,
+  },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, Order, Response, StdResult, Uint128, Uint64};
+
+/// Init state added in v0.0.6. Move events from the legacy events queue, which
+/// is ordered newest first, into the sequenced event log, and snapshot each
+/// staker's stake and the pool so that existing stake carries voting power.
+pub fn migrate(
+  deps: DepsMut,
+  env: Env,
+) -> ContractResult<Response> {
+  PENDING_CHANGE_SEQ_NO.save(deps.storage, &Uint64::zero())?;
+  PAUSED_SCOPES.save(deps.storage, &vec![])?;
+  RAKEBACK_TOTAL.save(deps.storage, &Uint128::zero())?;
+  REWARD_STREAM_SEQ_NO.save(deps.storage, &0)?;
+  N_STAKE_ACCOUNTS_UNBONDING_AT_RISK.save(deps.storage, &0)?;
+  PROPOSAL_SEQ_NO.save(deps.storage, &0)?;
+
+  let accounts = STAKE_ACCOUNTS
+    .range(deps.storage, None, None, Order::Ascending)
+    .collect::<StdResult<Vec<(Addr, StakeAccount)>>>()?;
+
+  for (addr, account) in accounts.iter() {
+    snapshot_stake(deps.storage, env.block.height, addr, account)?;
+  }
+
+  // Track which addresses are already queued for memoization.
+  let queued: Vec<Addr> = MEMOIZATION_QUEUE
+    .iter(deps.storage)?
+    .collect::<StdResult<Vec<Addr>>>()?;
+  for addr in queued.iter() {
+    MEMOIZATION_QUEUE_MEMBERS.save(deps.storage, addr.clone(), &true)?;
+  }
+
+  // All existing stake belongs to the senior tranche.
+  let mut pool = POOL.load(deps.storage)?;
+  pool.senior = Some(TrancheTotals {
+    delegation: pool.delegation,
+    liquidity: pool.liquidity,
+    multiplier: None,
+  });
+  pool.junior = Some(TrancheTotals::new());
+  POOL.save(deps.storage, &pool)?;
+  snapshot_pool(deps.storage, env.block.height, &pool)?;
+
+  let mut seq_no: u64 = 0;
+  while let Some(event) = EVENTS.pop_back(deps.storage)? {
+    seq_no += 1;
+    EVENT_LOG.save(
+      deps.storage,
+      seq_no,
+      &HouseEventRecord {
+        seq_no: seq_no.into(),
+        event,
+      },
+    )?;
+  }
+
+  EVENT_SEQ_NO.save(deps.storage, &seq_no.into())?;
+  EVENT_LOG_SIZE.save(deps.storage, &(seq_no as u32))?;
+
+  Ok(Response::default())
+}
@@ -1,8 +1,13 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, BlockInfo, Timestamp, Uint128, Uint64};
-use cw_lib::models::Token;
+use cosmwasm_std::{Addr, Api, Attribute, BlockInfo, Timestamp, Uint128, Uint64};
+use cw_lib::models::{Owner, Token};
 
-use crate::{error::ContractError, state::validate_address};
+use crate::{
+  error::ContractError,
+  msg::{ClientConfigUpdates, ClientInitArgs, ConfigUpdates},
+  state::{validate_address, DEFAULT_MAX_EVENT_LOG_SIZE, MAX_EVENT_LOG_SIZE, REWARD_INDEX_SCALE},
+  utils::{change_attrs, mul_pct, opt_to_string, MAX_LOCKUP_MULTIPLIER, PCT_DENOMINATOR},
+};
 
 #[cw_serde]
 pub struct Config {
@@ -11,6 +16,85 @@ pub struct Config {
   pub unbonding_seconds: Uint64,
   pub account_rate_limit: RateLimitConfig,
   pub default_client_rate_limit: RateLimitConfig,
+  pub timelock_seconds: Option<Uint64>,
+  pub max_event_log_size: Option<u32>,
+  pub large_payout_threshold: Option<Uint128>,
+  pub stats_bucket_seconds: Option<Uint64>,
+  pub stats_retention_buckets: Option<u32>,
+  pub player_stats_enabled: Option<bool>,
+  pub player_limits_delay_seconds: Option<Uint64>,
+  pub rakeback_rate: Option<Uint128>,
+  pub referral_rate: Option<Uint128>,
+  pub lockup_tiers: Option<Vec<LockupTier>>,
+  pub junior_revenue_multiplier: Option<Uint128>,
+  pub unbonding_shares_losses: Option<bool>,
+  pub unbonding_shares_gains: Option<bool>,
+  pub max_pool_delegation: Option<Uint128>,
+  pub min_stake: Option<Uint128>,
+  pub max_stake: Option<Uint128>,
+  pub staker_acl: Option<Addr>,
+  pub governance: Option<GovernanceConfig>,
+}
+
+/// Parameters of stake-weighted governance. Quorum is the share of total
+/// voting power that must vote, and threshold is the share of yes and no
+/// votes that must be yes, for a proposal to pass.
+#[cw_serde]
+pub struct GovernanceConfig {
+  pub voting_period_seconds: Uint64,
+  pub quorum: Uint128,
+  pub threshold: Uint128,
+  pub min_proposer_power: Option<Uint128>,
+}
+
+#[cw_serde]
+pub enum ProposalAction {
+  SetConfig(ConfigUpdates),
+  SetTaxes(Vec<TaxRecipient>),
+  Connect(ClientInitArgs),
+  Disconnect(Addr),
+}
+
+#[cw_serde]
+pub enum ProposalStatus {
+  Open,
+  Passed,
+  Rejected,
+  Executed,
+}
+
+#[cw_serde]
+pub enum Vote {
+  Yes,
+  No,
+  Abstain,
+}
+
+/// A set of actions that the house executes on its own behalf if stakers vote
+/// to pass it. Voting power is each staker's delegation at the snapshot height.
+#[cw_serde]
+pub struct Proposal {
+  pub id: Uint64,
+  pub proposer: Addr,
+  pub title: String,
+  pub description: Option<String>,
+  pub actions: Vec<ProposalAction>,
+  pub snapshot_height: Uint64,
+  pub total_power: Uint128,
+  pub quorum: Uint128,
+  pub threshold: Uint128,
+  pub created_at: Timestamp,
+  pub ends_at: Timestamp,
+  pub yes: Uint128,
+  pub no: Uint128,
+  pub abstain: Uint128,
+  pub executed: bool,
+}
+
+#[cw_serde]
+pub struct Ballot {
+  pub vote: Vote,
+  pub power: Uint128,
 }
 
 #[cw_serde]
@@ -37,6 +121,89 @@ pub enum HouseEvent {
     client: Addr,
     initiator: Addr,
   },
+  ClientConnected {
+    block: BlockInfo,
+    client: Addr,
+  },
+  ClientDisconnected {
+    block: BlockInfo,
+    client: Addr,
+  },
+  ClientSuspended {
+    block: BlockInfo,
+    client: Addr,
+    reason: String,
+  },
+  ClientResumed {
+    block: BlockInfo,
+    client: Addr,
+  },
+  BudgetExhausted {
+    block: BlockInfo,
+    client: Addr,
+  },
+  LargePayout {
+    block: BlockInfo,
+    client: Addr,
+    initiator: Addr,
+    amount: Uint128,
+  },
+  ConfigChanged {
+    block: BlockInfo,
+    sender: Addr,
+  },
+  TaxesPaid {
+    block: BlockInfo,
+    amount: Uint128,
+  },
+}
+
+#[cw_serde]
+pub struct HouseEventRecord {
+  pub seq_no: Uint64,
+  pub event: HouseEvent,
+}
+
+#[cw_serde]
+pub enum PauseScope {
+  Staking,
+  Unstaking,
+  Claims,
+  Process,
+  Revenue,
+  Credit,
+}
+
+#[cw_serde]
+pub enum HookTrigger {
+  StakingChanged,
+  LargeLoss,
+  ClientSuspended,
+  TaxesAccrued,
+}
+
+#[cw_serde]
+pub struct Hook {
+  pub address: Option<Addr>,
+  pub triggers: Vec<HookTrigger>,
+  pub loss_threshold: Option<Uint128>,
+  pub gas_limit: Option<u64>,
+}
+
+#[cw_serde]
+pub enum TimelockAction {
+  SetConfig(ConfigUpdates),
+  SetTaxes(Vec<TaxRecipient>),
+  ProposeOwner(Owner),
+}
+
+#[cw_serde]
+pub struct PendingChange {
+  pub id: Uint64,
+  pub action: TimelockAction,
+  pub proposer: Addr,
+  pub created_at: Timestamp,
+  pub eta: Timestamp,
 }
 
 #[cw_serde]
@@ -46,6 +213,23 @@ pub struct Pool {
   pub liquidity: Uint128,
   pub dividends: Uint128,
   pub taxes: Uint128,
+  pub senior: Option<TrancheTotals>,
+  pub junior: Option<TrancheTotals>,
+  pub unbonding: Option<TrancheTotals>,
+  pub junior_unbonding: Option<TrancheTotals>,
+}
+
+#[cw_serde]
+pub enum Tranche {
+  Senior,
+  Junior,
+}
+
+#[cw_serde]
+pub struct TrancheTotals {
+  pub delegation: Uint128,
+  pub liquidity: Uint128,
+  pub multiplier: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -57,6 +241,16 @@ pub struct StakeAccount {
   pub liquidity: Uint128,
   pub unbonding: Option<UnbondingInfo>,
   pub seq_no: Uint128,
+  pub lockup: Option<Lockup>,
+  pub tranche: Option<Tranche>,
+  pub reward_recipient: Option<Addr>,
+}
+
+/// A staker's or the pool's stake at a block height.
+#[cw_serde]
+pub struct StakeSnapshot {
+  pub delegation: Uint128,
+  pub liquidity: Uint128,
 }
 
 #[cw_serde]
@@ -65,10 +259,62 @@ pub struct BankAccount {
   pub balance: Uint128,
 }
 
+/// Lockup duration that a staker can choose in exchange for a multiplier on
+/// their share of dividends.
+#[cw_serde]
+pub struct LockupTier {
+  pub seconds: Uint64,
+  pub multiplier: Uint128,
+}
+
+#[cw_serde]
+pub struct Lockup {
+  pub expires_at: Timestamp,
+  pub multiplier: Uint128,
+}
+
+/// Incentive token emitted to stakers by reward streams. The index is the
+/// cumulative amount of the token earned per unit of delegation, scaled by
+/// REWARD_INDEX_SCALE.
+#[cw_serde]
+pub struct RewardToken {
+  pub token: Token,
+  pub index: Uint128,
+  pub updated_at: Timestamp,
+  pub funded: Uint128,
+  pub distributed: Uint128,
+  pub claimed: Uint128,
+}
+
+/// An amount of a reward token emitted linearly from start to end.
+#[cw_serde]
+pub struct RewardStream {
+  pub token: Token,
+  pub funder: Addr,
+  pub amount: Uint128,
+  pub emitted: Uint128,
+  pub start: Timestamp,
+  pub end: Timestamp,
+}
+
+/// A staker's accrued rewards in a single reward token.
+#[cw_serde]
+pub struct RewardAccount {
+  pub token: Option<Token>,
+  pub index: Uint128,
+  pub accrued: Uint128,
+  pub claimed: Uint128,
+}
+
 #[cw_serde]
 pub struct UnbondingInfo {
   pub amount: Uint128,
   pub time: Timestamp,
+  /// If set, the account's liquidity stays in the pool's unbonding bucket and
+  /// shares its losses until withdrawn. The amount excludes that liquidity.
+  pub at_risk: Option<bool>,
+  /// The account's delegation when it unstaked, restored if it rebonds.
+  pub delegation: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -78,6 +324,10 @@ pub struct ClientConfig {
   pub url: Option<String>,
   pub budget: Option<Uint128>,
   pub rate_limit: RateLimitConfig,
+  pub allowlist_only: Option<bool>,
+  pub rakeback_rate: Option<Uint128>,
+  pub revenue_share_pct: Option<Uint128>,
+  pub payout_address: Option<Addr>,
 }
 
 #[cw_serde]
@@ -88,6 +338,114 @@ pub struct Client {
   pub is_suspended: bool,
   pub revenue: Uint128,
   pub expense: Uint128,
+  pub revenue_share_balance: Option<Uint128>,
+  pub revenue_share_claimed: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct ClientStatsBucket {
+  pub start: Timestamp,
+  pub revenue: Uint128,
+  pub expense: Uint128,
+  pub n_jobs: u32,
+  pub n_initiators: u32,
+  pub max_payout: Uint128,
+}
+
+#[cw_serde]
+pub struct PlayerStats {
+  pub address: Option<Addr>,
+  pub incoming: Uint128,
+  pub outgoing: Uint128,
+  pub n_jobs: u32,
+  pub first_seen: Timestamp,
+  pub last_seen: Timestamp,
+}
+
+#[cw_serde]
+pub struct PlayerClientStats {
+  pub client: Option<Addr>,
+  pub incoming: Uint128,
+  pub outgoing: Uint128,
+  pub n_jobs: u32,
+}
+
+#[cw_serde]
+pub enum PlayerStatsOrderBy {
+  Address,
+  Incoming,
+  Outgoing,
+}
+
+#[cw_serde]
+pub enum LimitPeriod {
+  Daily,
+  Weekly,
+}
+
+#[cw_serde]
+pub struct PeriodLimit {
+  pub period: LimitPeriod,
+  pub amount: Uint128,
+}
+
+/// Responsible-gaming limits set by a player for themselves.
+#[cw_serde]
+pub struct PlayerLimits {
+  pub excluded_until: Option<Timestamp>,
+  pub loss_limit: Option<PeriodLimit>,
+  pub wager_limit: Option<PeriodLimit>,
+}
+
+/// Player limits that loosen existing limits, waiting to take effect.
+#[cw_serde]
+pub struct PendingPlayerLimits {
+  pub limits: PlayerLimits,
+  pub eta: Timestamp,
+}
+
+#[cw_serde]
+pub struct PeriodUsage {
+  pub start: Timestamp,
+  pub amount: Uint128,
+}
+
+/// Entry in the initiator denylist or a client's allowlist.
+#[cw_serde]
+pub struct InitiatorListEntry {
+  pub reason: Option<String>,
+  pub created_at: Timestamp,
+  pub expires_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct ReferralAccount {
+  pub address: Option<Addr>,
+  pub n_referrals: u32,
+  pub earned: Uint128,
+  pub claimed: Uint128,
+  pub balance: Uint128,
+}
+
+/// Separately accounted prize pool, fed by a percentage of revenue from its
+/// client, or from all clients if it has none. Only its client can pay out of
+/// it, or any client if it has none.
+#[cw_serde]
+pub struct Jackpot {
+  pub name: String,
+  pub client: Option<Addr>,
+  pub contribution_pct: Uint128,
+  pub balance: Uint128,
+  pub contributed: Uint128,
+  pub paid_out: Uint128,
+  pub created_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct JackpotContribution {
+  pub client: Addr,
+  pub amount: Uint128,
+  pub paid_out: Uint128,
 }
 
 #[cw_serde]
@@ -107,6 +465,21 @@ pub struct LedgerEntry {
   pub delta_loss: Uint128,
   pub ref_count: u32,
   pub tag: Uint128,
+  pub multiplier: Option<Uint128>,
+  pub junior: Option<TrancheLedgerEntry>,
+  pub unbonding: Option<TrancheLedgerEntry>,
+  pub junior_unbonding: Option<TrancheLedgerEntry>,
+}
+
+/// Ledger accounting for a single tranche. The top-level fields of a
+/// LedgerEntry hold the senior tranche's accounting.
+#[cw_serde]
+pub struct TrancheLedgerEntry {
+  pub liquidity: Uint128,
+  pub delta_revenue: Uint128,
+  pub delta_dividends: Uint128,
+  pub delta_loss: Uint128,
+  pub multiplier: Option<Uint128>,
 }
 
 pub struct LedgerUpdates {
@@ -185,6 +558,474 @@ impl AccountTokenAmount {
   }
 }
 
+impl Config {
+  pub fn validate(&self) -> Result<(), ContractError> {
+    if self.restake_rate > Uint128::from(PCT_DENOMINATOR) {
+      return Err(ContractError::InvalidRestakeRate);
+    }
+    if self.tax_rate > Uint128::from(PCT_DENOMINATOR) {
+      return Err(ContractError::InvalidTaxRate);
+    }
+    // A timelock must give stakers time to unbond before a queued change
+    // takes effect.
+    if let Some(seconds) = self.timelock_seconds {
+      if !seconds.is_zero() && seconds < self.unbonding_seconds {
+        return Err(ContractError::InvalidTimelockSeconds);
+      }
+    }
+    if let Some(rate) = self.rakeback_rate {
+      if rate > Uint128::from(PCT_DENOMINATOR) {
+        return Err(ContractError::InvalidRakebackRate);
+      }
+    }
+    if let Some(rate) = self.referral_rate {
+      if rate > Uint128::from(PCT_DENOMINATOR) {
+        return Err(ContractError::InvalidReferralRate);
+      }
+    }
+    if let Some(tiers) = &self.lockup_tiers {
+      for tier in tiers.iter() {
+        tier.validate()?;
+      }
+    }
+    if let Some(multiplier) = self.junior_revenue_multiplier {
+      if multiplier < Uint128::from(PCT_DENOMINATOR)
+        || multiplier > Uint128::from(MAX_LOCKUP_MULTIPLIER)
+      {
+        return Err(ContractError::InvalidJuniorRevenueMultiplier);
+      }
+    }
+    if let (Some(min_stake), Some(max_stake)) = (self.min_stake, self.max_stake) {
+      if min_stake > max_stake {
+        return Err(ContractError::InvalidStakeLimits);
+      }
+    }
+    if let Some(max_stake) = self.max_stake {
+      if max_stake.is_zero() {
+        return Err(ContractError::InvalidStakeLimits);
+      }
+    }
+    if let Some(governance) = &self.governance {
+      governance.validate()?;
+    }
+    if let Some(seconds) = self.stats_bucket_seconds {
+      if seconds.is_zero() {
+        return Err(ContractError::InvalidStatsBucketSeconds);
+      }
+    }
+    if let Some(size) = self.max_event_log_size {
+      if size > MAX_EVENT_LOG_SIZE {
+        return Err(ContractError::InvalidMaxEventLogSize);
+      }
+    }
+    self.account_rate_limit.validate()?;
+    self.default_client_rate_limit.validate()?;
+    Ok(())
+  }
+
+  /// Apply only the fields set in the given updates, returning attributes with
+  /// the before and after value of each changed field.
+  pub fn apply_updates(
+    &mut self,
+    updates: &ConfigUpdates,
+  ) -> Vec<Attribute> {
+    let mut attrs: Vec<Attribute> = vec![];
+    if let Some(restake_rate) = updates.restake_rate {
+      attrs.extend(change_attrs(
+        "restake_rate",
+        self.restake_rate.to_string(),
+        restake_rate.to_string(),
+      ));
+      self.restake_rate = restake_rate;
+    }
+    if let Some(tax_rate) = updates.tax_rate {
+      attrs.extend(change_attrs(
+        "tax_rate",
+        self.tax_rate.to_string(),
+        tax_rate.to_string(),
+      ));
+      self.tax_rate = tax_rate;
+    }
+    if let Some(unbonding_seconds) = updates.unbonding_seconds {
+      attrs.extend(change_attrs(
+        "unbonding_seconds",
+        self.unbonding_seconds.to_string(),
+        unbonding_seconds.to_string(),
+      ));
+      self.unbonding_seconds = unbonding_seconds;
+    }
+    if let Some(timelock_seconds) = updates.timelock_seconds {
+      attrs.extend(change_attrs(
+        "timelock_seconds",
+        self.timelock_seconds.unwrap_or_default().to_string(),
+        timelock_seconds.to_string(),
+      ));
+      self.timelock_seconds = Some(timelock_seconds);
+    }
+    if let Some(max_event_log_size) = updates.max_event_log_size {
+      attrs.extend(change_attrs(
+        "max_event_log_size",
+        self
+          .max_event_log_size
+          .unwrap_or(DEFAULT_MAX_EVENT_LOG_SIZE)
+          .to_string(),
+        max_event_log_size.to_string(),
+      ));
+      self.max_event_log_size = Some(max_event_log_size);
+    }
+    if let Some(threshold) = updates.large_payout_threshold {
+      attrs.extend(change_attrs(
+        "large_payout_threshold",
+        self
+          .large_payout_threshold
+          .map(|x| x.to_string())
+          .unwrap_or_default(),
+        threshold.to_string(),
+      ));
+      self.large_payout_threshold = Some(threshold);
+    }
+    if let Some(seconds) = updates.stats_bucket_seconds {
+      attrs.extend(change_attrs(
+        "stats_bucket_seconds",
+        opt_to_string(&self.stats_bucket_seconds),
+        seconds.to_string(),
+      ));
+      self.stats_bucket_seconds = Some(seconds);
+    }
+    if let Some(n_buckets) = updates.stats_retention_buckets {
+      attrs.extend(change_attrs(
+        "stats_retention_buckets",
+        opt_to_string(&self.stats_retention_buckets),
+        n_buckets.to_string(),
+      ));
+      self.stats_retention_buckets = Some(n_buckets);
+    }
+    if let Some(enabled) = updates.player_stats_enabled {
+      attrs.extend(change_attrs(
+        "player_stats_enabled",
+        opt_to_string(&self.player_stats_enabled),
+        enabled.to_string(),
+      ));
+      self.player_stats_enabled = Some(enabled);
+    }
+    if let Some(seconds) = updates.player_limits_delay_seconds {
+      attrs.extend(change_attrs(
+        "player_limits_delay_seconds",
+        opt_to_string(&self.player_limits_delay_seconds),
+        seconds.to_string(),
+      ));
+      self.player_limits_delay_seconds = Some(seconds);
+    }
+    if let Some(rate) = updates.rakeback_rate {
+      attrs.extend(change_attrs(
+        "rakeback_rate",
+        opt_to_string(&self.rakeback_rate),
+        rate.to_string(),
+      ));
+      self.rakeback_rate = Some(rate);
+    }
+    if let Some(rate) = updates.referral_rate {
+      attrs.extend(change_attrs(
+        "referral_rate",
+        opt_to_string(&self.referral_rate),
+        rate.to_string(),
+      ));
+      self.referral_rate = Some(rate);
+    }
+    if let Some(tiers) = &updates.lockup_tiers {
+      attrs.extend(change_attrs(
+        "lockup_tiers",
+        LockupTier::to_attr_value(&self.lockup_tiers.clone().unwrap_or_default()),
+        LockupTier::to_attr_value(tiers),
+      ));
+      self.lockup_tiers = Some(tiers.clone());
+    }
+    if let Some(multiplier) = updates.junior_revenue_multiplier {
+      attrs.extend(change_attrs(
+        "junior_revenue_multiplier",
+        opt_to_string(&self.junior_revenue_multiplier),
+        multiplier.to_string(),
+      ));
+      self.junior_revenue_multiplier = Some(multiplier);
+    }
+    if let Some(enabled) = updates.unbonding_shares_losses {
+      attrs.extend(change_attrs(
+        "unbonding_shares_losses",
+        opt_to_string(&self.unbonding_shares_losses),
+        enabled.to_string(),
+      ));
+      self.unbonding_shares_losses = Some(enabled);
+    }
+    if let Some(enabled) = updates.unbonding_shares_gains {
+      attrs.extend(change_attrs(
+        "unbonding_shares_gains",
+        opt_to_string(&self.unbonding_shares_gains),
+        enabled.to_string(),
+      ));
+      self.unbonding_shares_gains = Some(enabled);
+    }
+    if let Some(update) = &updates.max_pool_delegation {
+      let amount = update.value();
+      attrs.extend(change_attrs(
+        "max_pool_delegation",
+        opt_to_string(&self.max_pool_delegation),
+        opt_to_string(&amount),
+      ));
+      self.max_pool_delegation = amount;
+    }
+    if let Some(update) = &updates.min_stake {
+      let amount = update.value();
+      attrs.extend(change_attrs(
+        "min_stake",
+        opt_to_string(&self.min_stake),
+        opt_to_string(&amount),
+      ));
+      self.min_stake = amount;
+    }
+    if let Some(update) = &updates.max_stake {
+      let amount = update.value();
+      attrs.extend(change_attrs(
+        "max_stake",
+        opt_to_string(&self.max_stake),
+        opt_to_string(&amount),
+      ));
+      self.max_stake = amount;
+    }
+    if let Some(governance) = &updates.governance {
+      attrs.extend(change_attrs(
+        "governance",
+        self
+          .governance
+          .as_ref()
+          .map(|g| g.to_attr_value())
+          .unwrap_or_default(),
+        governance.to_attr_value(),
+      ));
+      self.governance = Some(governance.clone());
+    }
+    if let Some(update) = &updates.staker_acl {
+      let acl = update.value();
+      attrs.extend(change_attrs(
+        "staker_acl",
+        opt_to_string(&self.staker_acl),
+        opt_to_string(&acl),
+      ));
+      self.staker_acl = acl;
+    }
+    if let Some(rate_limit) = &updates.account_rate_limit {
+      attrs.extend(
+        self
+          .account_rate_limit
+          .change_attrs("account_rate_limit", rate_limit),
+      );
+      self.account_rate_limit = rate_limit.clone();
+    }
+    if let Some(rate_limit) = &updates.default_client_rate_limit {
+      attrs.extend(
+        self
+          .default_client_rate_limit
+          .change_attrs("default_client_rate_limit", rate_limit),
+      );
+      self.default_client_rate_limit = rate_limit.clone();
+    }
+    attrs
+  }
+}
+
+impl HouseEvent {
+  /// Name of the event variant, used to filter the event log.
+  pub fn kind(&self) -> &'static str {
+    match self {
+      HouseEvent::ClientRateLimitTriggered { .. } => "client_rate_limit_triggered",
+      HouseEvent::AccountRateLimitTriggered { .. } => "account_rate_limit_triggered",
+      HouseEvent::ClientConnected { .. } => "client_connected",
+      HouseEvent::ClientDisconnected { .. } => "client_disconnected",
+      HouseEvent::ClientSuspended { .. } => "client_suspended",
+      HouseEvent::ClientResumed { .. } => "client_resumed",
+      HouseEvent::BudgetExhausted { .. } => "budget_exhausted",
+      HouseEvent::LargePayout { .. } => "large_payout",
+      HouseEvent::ConfigChanged { .. } => "config_changed",
+      HouseEvent::TaxesPaid { .. } => "taxes_paid",
+    }
+  }
+
+  /// Address of the client contract involved in the event, if any.
+  pub fn client(&self) -> Option<&Addr> {
+    match self {
+      HouseEvent::ClientRateLimitTriggered { client, .. }
+      | HouseEvent::AccountRateLimitTriggered { client, .. }
+      | HouseEvent::ClientConnected { client, .. }
+      | HouseEvent::ClientDisconnected { client, .. }
+      | HouseEvent::ClientSuspended { client, .. }
+      | HouseEvent::ClientResumed { client, .. }
+      | HouseEvent::BudgetExhausted { client, .. }
+      | HouseEvent::LargePayout { client, .. } => Some(client),
+      HouseEvent::ConfigChanged { .. } | HouseEvent::TaxesPaid { .. } => None,
+    }
+  }
+}
+
+impl PendingChange {
+  /// Return true if the change's delay has elapsed.
+  pub fn is_ready(
+    &self,
+    time: Timestamp,
+  ) -> bool {
+    time >= self.eta
+  }
+}
+
+impl RateLimitConfig {
+  pub fn validate(&self) -> Result<(), ContractError> {
+    if self.interval_seconds.is_zero() {
+      return Err(ContractError::InvalidIntervalSeconds);
+    }
+    // A zero max_pct_change would throttle every payout, and anything above
+    // 100% would never throttle at all.
+    if self.max_pct_change.is_zero() || self.max_pct_change > Uint128::from(PCT_DENOMINATOR) {
+      return Err(ContractError::InvalidMaxPctChange);
+    }
+    Ok(())
+  }
+
+  pub fn change_attrs(
+    &self,
+    key: &str,
+    other: &RateLimitConfig,
+  ) -> Vec<Attribute> {
+    let mut attrs = change_attrs(
+      &format!("{}.interval_seconds", key),
+      self.interval_seconds.to_string(),
+      other.interval_seconds.to_string(),
+    );
+    attrs.extend(change_attrs(
+      &format!("{}.max_pct_change", key),
+      self.max_pct_change.to_string(),
+      other.max_pct_change.to_string(),
+    ));
+    attrs
+  }
+}
+
+impl ClientConfig {
+  /// Validate the config. A zero budget is only rejected where it's set, in
+  /// init args or updates, since a client's budget is used up to zero.
+  pub fn validate(&self) -> Result<(), ContractError> {
+    if let Some(rate) = self.rakeback_rate {
+      if rate > Uint128::from(PCT_DENOMINATOR) {
+        return Err(ContractError::InvalidRakebackRate);
+      }
+    }
+    if let Some(pct) = self.revenue_share_pct {
+      if pct > Uint128::from(PCT_DENOMINATOR) {
+        return Err(ContractError::InvalidRevenueSharePct);
+      }
+    }
+    self.rate_limit.validate()?;
+    Ok(())
+  }
+
+  /// Apply only the fields set in the given updates, returning attributes with
+  /// the before and after value of each changed field.
+  pub fn apply_updates(
+    &mut self,
+    updates: &ClientConfigUpdates,
+  ) -> Result<Vec<Attribute>, ContractError> {
+    let mut attrs: Vec<Attribute> = vec![];
+    if let Some(name) = &updates.name {
+      attrs.extend(change_attrs(
+        "name",
+        self.name.clone().unwrap_or_default(),
+        name.clone(),
+      ));
+      self.name = Some(name.clone());
+    }
+    if let Some(description) = &updates.description {
+      attrs.extend(change_attrs(
+        "description",
+        self.description.clone().unwrap_or_default(),
+        description.clone(),
+      ));
+      self.description = Some(description.clone());
+    }
+    if let Some(url) = &updates.url {
+      attrs.extend(change_attrs(
+        "url",
+        self.url.clone().unwrap_or_default(),
+        url.clone(),
+      ));
+      self.url = Some(url.clone());
+    }
+    if let Some(update) = &updates.budget {
+      let budget = update.value();
+      if budget == Some(Uint128::zero()) {
+        return Err(ContractError::InvalidClientBudget);
+      }
+      attrs.extend(change_attrs(
+        "budget",
+        opt_to_string(&self.budget),
+        opt_to_string(&budget),
+      ));
+      self.budget = budget;
+    }
+    if let Some(rate_limit) = &updates.rate_limit {
+      attrs.extend(self.rate_limit.change_attrs("rate_limit", rate_limit));
+      self.rate_limit = rate_limit.clone();
+    }
+    if let Some(allowlist_only) = updates.allowlist_only {
+      attrs.extend(change_attrs(
+        "allowlist_only",
+        opt_to_string(&self.allowlist_only),
+        allowlist_only.to_string(),
+      ));
+      self.allowlist_only = Some(allowlist_only);
+    }
+    if let Some(rate) = updates.rakeback_rate {
+      attrs.extend(change_attrs(
+        "rakeback_rate",
+        opt_to_string(&self.rakeback_rate),
+        rate.to_string(),
+      ));
+      self.rakeback_rate = Some(rate);
+    }
+    if let Some(pct) = updates.revenue_share_pct {
+      attrs.extend(change_attrs(
+        "revenue_share_pct",
+        opt_to_string(&self.revenue_share_pct),
+        pct.to_string(),
+      ));
+      self.revenue_share_pct = Some(pct);
+    }
+    if let Some(update) = &updates.payout_address {
+      let addr = update.value();
+      attrs.extend(change_attrs(
+        "payout_address",
+        opt_to_string(&self.payout_address),
+        opt_to_string(&addr),
+      ));
+      self.payout_address = addr;
+    }
+    Ok(attrs)
+  }
+}
+
+impl TaxRecipient {
+  pub fn validate(
+    &self,
+    api: &dyn Api,
+  ) -> Result<(), ContractError> {
+    if let Some(addr) = &self.address {
+      validate_address(api, addr)?;
+    } else {
+      return Err(ContractError::MissingTaxRecipientAddress);
+    }
+    if self.pct.is_zero() || self.pct > Uint128::from(PCT_DENOMINATOR) {
+      return Err(ContractError::InvalidTaxRecipientPct);
+    }
+    Ok(())
+  }
+}
+
 impl StakeAccount {
   pub fn new(
     delegation: Uint128,
@@ -198,6 +1039,9 @@ impl StakeAccount {
       address: None,
       unbonding: None,
       is_suspended: Some(false),
+      lockup: None,
+      tranche: None,
+      reward_recipient: None,
     }
   }
 }
@@ -218,12 +1062,18 @@ impl Client {
       expense: Uint128::zero(),
       revenue: Uint128::zero(),
       is_suspended: false,
+      revenue_share_balance: None,
+      revenue_share_claimed: None,
       config: ClientConfig {
         name,
         rate_limit,
         description,
         url,
         budget,
+        allowlist_only: None,
+        rakeback_rate: None,
+        revenue_share_pct: None,
+        payout_address: None,
       },
     }
   }
@@ -250,7 +1100,384 @@ impl Pool {
       delegation: Uint128::zero(),
       dividends: Uint128::zero(),
       taxes: Uint128::zero(),
+      senior: Some(TrancheTotals::new()),
+      junior: Some(TrancheTotals::new()),
+      unbonding: None,
+      junior_unbonding: None,
       token: token.clone(),
     }
   }
+
+  /// Return the totals for the given tranche. Both tranches are set when the
+  /// pool is created or migrated.
+  pub fn tranche(
+    &self,
+    tranche: &Tranche,
+  ) -> TrancheTotals {
+    match tranche {
+      Tranche::Senior => self.senior.clone(),
+      Tranche::Junior => self.junior.clone(),
+    }
+    .unwrap_or_else(|| TrancheTotals::new())
+  }
+
+  /// Return the totals of liquidity that is unbonding from the given tranche
+  /// but still at risk.
+  pub fn unbonding_totals(
+    &self,
+    tranche: &Tranche,
+  ) -> TrancheTotals {
+    match tranche {
+      Tranche::Senior => self.unbonding.clone(),
+      Tranche::Junior => self.junior_unbonding.clone(),
+    }
+    .unwrap_or_else(|| TrancheTotals::new())
+  }
+
+  pub fn set_unbonding_totals(
+    &mut self,
+    tranche: &Tranche,
+    totals: TrancheTotals,
+  ) {
+    match tranche {
+      Tranche::Senior => self.unbonding = Some(totals),
+      Tranche::Junior => self.junior_unbonding = Some(totals),
+    }
+  }
+
+  pub fn set_tranche(
+    &mut self,
+    tranche: &Tranche,
+    totals: TrancheTotals,
+  ) {
+    match tranche {
+      Tranche::Senior => self.senior = Some(totals),
+      Tranche::Junior => self.junior = Some(totals),
+    }
+  }
+}
+
+impl TrancheTotals {
+  pub fn new() -> Self {
+    Self {
+      delegation: Uint128::zero(),
+      liquidity: Uint128::zero(),
+      multiplier: None,
+    }
+  }
+}
+
+impl LimitPeriod {
+  pub fn seconds(&self) -> u64 {
+    match self {
+      LimitPeriod::Daily => 60 * 60 * 24,
+      LimitPeriod::Weekly => 60 * 60 * 24 * 7,
+    }
+  }
+}
+
+impl PeriodLimit {
+  pub fn validate(&self) -> Result<(), ContractError> {
+    if self.amount.is_zero() {
+      return Err(ContractError::InvalidPlayerLimits);
+    }
+    Ok(())
+  }
+
+  /// Return the stricter of two optional limits along with a flag that's true
+  /// if the new limit loosens the current one. Limits with different periods
+  /// aren't comparable, so changing the period counts as loosening.
+  fn tighten(
+    current: &Option<PeriodLimit>,
+    new: &Option<PeriodLimit>,
+  ) -> (Option<PeriodLimit>, bool) {
+    match (current, new) {
+      (None, _) => (new.clone(), false),
+      (Some(_), None) => (current.clone(), true),
+      (Some(a), Some(b)) => {
+        if a.period == b.period && b.amount <= a.amount {
+          (new.clone(), false)
+        } else {
+          (current.clone(), true)
+        }
+      },
+    }
+  }
+}
+
+impl PlayerLimits {
+  pub fn validate(&self) -> Result<(), ContractError> {
+    if let Some(limit) = &self.loss_limit {
+      limit.validate()?;
+    }
+    if let Some(limit) = &self.wager_limit {
+      limit.validate()?;
+    }
+    Ok(())
+  }
+
+  /// Merge new limits into the current ones, taking whichever is stricter for
+  /// each limit. Returns the merged limits and true if any new limit loosens
+  /// the current one, in which case the new limits must wait to take effect.
+  /// An exclusion that hasn't expired can only be extended, never shortened.
+  pub fn tighten(
+    &self,
+    new: &PlayerLimits,
+    time: Timestamp,
+  ) -> (PlayerLimits, bool) {
+    let (loss_limit, is_loss_loosened) = PeriodLimit::tighten(&self.loss_limit, &new.loss_limit);
+    let (wager_limit, is_wager_loosened) =
+      PeriodLimit::tighten(&self.wager_limit, &new.wager_limit);
+    (
+      PlayerLimits {
+        excluded_until: self.extend_exclusion(new.excluded_until, time),
+        loss_limit,
+        wager_limit,
+      },
+      is_loss_loosened || is_wager_loosened,
+    )
+  }
+
+  /// Apply pending limits whose delay has elapsed to the current ones. Only
+  /// the limits that the pending ones loosen change, and an exclusion that
+  /// hasn't expired is kept.
+  pub fn loosen(
+    &self,
+    pending: &PlayerLimits,
+    time: Timestamp,
+  ) -> PlayerLimits {
+    let (_, is_loss_loosened) = PeriodLimit::tighten(&self.loss_limit, &pending.loss_limit);
+    let (_, is_wager_loosened) = PeriodLimit::tighten(&self.wager_limit, &pending.wager_limit);
+    PlayerLimits {
+      excluded_until: self.extend_exclusion(pending.excluded_until, time),
+      loss_limit: if is_loss_loosened {
+        pending.loss_limit.clone()
+      } else {
+        self.loss_limit.clone()
+      },
+      wager_limit: if is_wager_loosened {
+        pending.wager_limit.clone()
+      } else {
+        self.wager_limit.clone()
+      },
+    }
+  }
+
+  /// Return the later of the current exclusion, if it hasn't expired, and the
+  /// given one.
+  fn extend_exclusion(
+    &self,
+    excluded_until: Option<Timestamp>,
+    time: Timestamp,
+  ) -> Option<Timestamp> {
+    match (self.excluded_until.filter(|t| *t > time), excluded_until) {
+      (Some(a), Some(b)) => Some(a.max(b)),
+      (Some(a), None) => Some(a),
+      (None, b) => b,
+    }
+  }
+}
+
+impl InitiatorListEntry {
+  /// Return true if the entry hasn't expired.
+  pub fn is_active(
+    &self,
+    time: Timestamp,
+  ) -> bool {
+    self.expires_at.map(|t| time < t).unwrap_or(true)
+  }
+}
+
+impl ReferralAccount {
+  pub fn new() -> Self {
+    Self {
+      address: None,
+      n_referrals: 0,
+      earned: Uint128::zero(),
+      claimed: Uint128::zero(),
+      balance: Uint128::zero(),
+    }
+  }
+}
+
+impl LockupTier {
+  pub fn validate(&self) -> Result<(), ContractError> {
+    // Multipliers below 1x would let stakers lock up for less than nothing,
+    // and anything above the cap would let one staker crowd out the rest.
+    if self.seconds.is_zero()
+      || self.multiplier < Uint128::from(PCT_DENOMINATOR)
+      || self.multiplier > Uint128::from(MAX_LOCKUP_MULTIPLIER)
+    {
+      return Err(ContractError::InvalidLockupTier);
+    }
+    Ok(())
+  }
+
+  pub fn to_attr_value(tiers: &Vec<LockupTier>) -> String {
+    tiers
+      .iter()
+      .map(|t| format!("{}:{}", t.seconds, t.multiplier))
+      .collect::<Vec<String>>()
+      .join(",")
+  }
+}
+
+impl StakeAccount {
+  /// Return the address to which the staker's claims and withdrawals are sent.
+  pub fn recipient(
+    &self,
+    staker: &Addr,
+  ) -> Addr {
+    self
+      .reward_recipient
+      .clone()
+      .unwrap_or_else(|| staker.clone())
+  }
+
+  /// Return true if the account is unbonding with liquidity still at risk.
+  pub fn is_unbonding_at_risk(&self) -> bool {
+    self
+      .unbonding
+      .as_ref()
+      .and_then(|unbonding| unbonding.at_risk)
+      .unwrap_or(false)
+  }
+
+  /// Return the account's tranche, which is senior unless it chose otherwise.
+  pub fn tranche(&self) -> Tranche {
+    self.tranche.clone().unwrap_or(Tranche::Senior)
+  }
+
+  /// Return the multiplier by which the account's liquidity is weighted in
+  /// its tranche, which is 1x without a lockup. An expired lockup keeps its
+  /// multiplier until the account is next synced, when it's released.
+  pub fn multiplier(&self) -> Uint128 {
+    self
+      .lockup
+      .as_ref()
+      .map(|l| l.multiplier)
+      .unwrap_or(Uint128::from(PCT_DENOMINATOR))
+  }
+}
+
+impl LedgerEntry {
+  /// Return the senior tranche's accounting from the entry's top-level fields.
+  pub fn senior(&self) -> TrancheLedgerEntry {
+    TrancheLedgerEntry {
+      liquidity: self.liquidity,
+      delta_revenue: self.delta_revenue,
+      delta_dividends: self.delta_dividends,
+      delta_loss: self.delta_loss,
+      multiplier: self.multiplier,
+    }
+  }
+
+  /// Return the accounting of at-risk liquidity unbonding from the given
+  /// tranche.
+  pub fn unbonding_entry(
+    &self,
+    tranche: &Tranche,
+  ) -> TrancheLedgerEntry {
+    match tranche {
+      Tranche::Senior => self.unbonding.clone(),
+      Tranche::Junior => self.junior_unbonding.clone(),
+    }
+    .unwrap_or_else(|| TrancheLedgerEntry::new(Uint128::zero(), None))
+  }
+}
+
+impl TrancheLedgerEntry {
+  pub fn new(
+    liquidity: Uint128,
+    multiplier: Option<Uint128>,
+  ) -> Self {
+    Self {
+      liquidity,
+      multiplier,
+      delta_revenue: Uint128::zero(),
+      delta_dividends: Uint128::zero(),
+      delta_loss: Uint128::zero(),
+    }
+  }
+}
+
+impl RewardAccount {
+  pub fn new() -> Self {
+    Self {
+      token: None,
+      index: Uint128::zero(),
+      accrued: Uint128::zero(),
+      claimed: Uint128::zero(),
+    }
+  }
+
+  /// Accrue the account's share of a reward token's emissions since the
+  /// account last settled, given its delegation over that period.
+  pub fn accrue(
+    &mut self,
+    reward_token: &RewardToken,
+    delegation: Uint128,
+  ) {
+    self.accrued += delegation.multiply_ratio(reward_token.index - self.index, REWARD_INDEX_SCALE);
+    self.index = reward_token.index;
+  }
+}
+
+impl GovernanceConfig {
+  pub fn validate(&self) -> Result<(), ContractError> {
+    if self.voting_period_seconds.is_zero()
+      || self.quorum > Uint128::from(PCT_DENOMINATOR)
+      || self.threshold.is_zero()
+      || self.threshold > Uint128::from(PCT_DENOMINATOR)
+    {
+      return Err(ContractError::InvalidGovernanceConfig);
+    }
+    Ok(())
+  }
+
+  pub fn to_attr_value(&self) -> String {
+    format!(
+      "{}:{}:{}:{}",
+      self.voting_period_seconds,
+      self.quorum,
+      self.threshold,
+      opt_to_string(&self.min_proposer_power)
+    )
+  }
+}
+
+impl Proposal {
+  /// Return the proposal's status at the given time. A proposal passes if,
+  /// once voting has ended, enough voting power voted to meet quorum and the
+  /// share of yes votes meets the threshold.
+  pub fn status(
+    &self,
+    time: Timestamp,
+  ) -> ProposalStatus {
+    if self.executed {
+      return ProposalStatus::Executed;
+    }
+    if time < self.ends_at {
+      return ProposalStatus::Open;
+    }
+    let turnout = self.yes + self.no + self.abstain;
+    let quorum_met = turnout >= mul_pct(self.total_power, self.quorum) && !turnout.is_zero();
+    let threshold_met =
+      self.yes >= mul_pct(self.yes + self.no, self.threshold) && !self.yes.is_zero();
+    if quorum_met && threshold_met {
+      ProposalStatus::Passed
+    } else {
+      ProposalStatus::Rejected
+    }
+  }
+}
+
+impl Vote {
+  pub fn as_str(&self) -> &str {
+    match self {
+      Vote::Yes => "yes",
+      Vote::No => "no",
+      Vote::Abstain => "abstain",
+    }
+  }
 }
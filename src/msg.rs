@@ -3,8 +3,11 @@ use cosmwasm_std::{Addr, Timestamp, Uint128, Uint64};
 use cw_lib::models::{Owner, Token};
 
 use crate::models::{
-  AccountTokenAmount, BankAccount, Client, ClientConfig, Config, HouseEvent, LedgerEntry, Pool,
-  RateLimitConfig, StakeAccount, TaxRecipient,
+  AccountTokenAmount, Ballot, BankAccount, Client, ClientConfig, Config, GovernanceConfig, Hook,
+  HookTrigger, HouseEventRecord, Jackpot, JackpotContribution, LedgerEntry, LockupTier, PauseScope,
+  PendingChange, PendingPlayerLimits, PlayerClientStats, PlayerLimits, PlayerStats,
+  PlayerStatsOrderBy, Pool, Proposal, ProposalAction, ProposalStatus, RateLimitConfig,
+  ReferralAccount, RewardAccount, StakeAccount, TaxRecipient, Tranche, Vote,
 };
 
 #[cw_serde]
@@ -25,60 +28,284 @@ pub struct ClientInitArgs {
   pub rate_limit: Option<RateLimitConfig>,
 }
 
+#[cw_serde]
+pub struct ConfigUpdates {
+  pub restake_rate: Option<Uint128>,
+  pub tax_rate: Option<Uint128>,
+  pub unbonding_seconds: Option<Uint64>,
+  pub account_rate_limit: Option<RateLimitConfig>,
+  pub default_client_rate_limit: Option<RateLimitConfig>,
+  pub timelock_seconds: Option<Uint64>,
+  pub max_event_log_size: Option<u32>,
+  pub large_payout_threshold: Option<Uint128>,
+  pub stats_bucket_seconds: Option<Uint64>,
+  pub stats_retention_buckets: Option<u32>,
+  pub player_stats_enabled: Option<bool>,
+  pub player_limits_delay_seconds: Option<Uint64>,
+  pub rakeback_rate: Option<Uint128>,
+  pub referral_rate: Option<Uint128>,
+  pub lockup_tiers: Option<Vec<LockupTier>>,
+  pub junior_revenue_multiplier: Option<Uint128>,
+  pub unbonding_shares_losses: Option<bool>,
+  pub unbonding_shares_gains: Option<bool>,
+  pub max_pool_delegation: Option<OptionalUpdate<Uint128>>,
+  pub min_stake: Option<OptionalUpdate<Uint128>>,
+  pub max_stake: Option<OptionalUpdate<Uint128>>,
+  pub staker_acl: Option<OptionalUpdate<Addr>>,
+  pub governance: Option<GovernanceConfig>,
+}
+
+/// Update to an optional field, which either sets it or clears it to None.
+/// Fields whose update is omitted are left unchanged.
+#[cw_serde]
+pub enum OptionalUpdate<T> {
+  Set(T),
+  Clear,
+}
+
+impl<T: Clone> OptionalUpdate<T> {
+  /// Return the field's value after the update.
+  pub fn value(&self) -> Option<T> {
+    match self {
+      OptionalUpdate::Set(value) => Some(value.clone()),
+      OptionalUpdate::Clear => None,
+    }
+  }
+}
+
+#[cw_serde]
+pub struct ClientConfigUpdates {
+  pub name: Option<String>,
+  pub description: Option<String>,
+  pub url: Option<String>,
+  pub budget: Option<OptionalUpdate<Uint128>>,
+  pub rate_limit: Option<RateLimitConfig>,
+  pub allowlist_only: Option<bool>,
+  pub rakeback_rate: Option<Uint128>,
+  pub revenue_share_pct: Option<Uint128>,
+  pub payout_address: Option<OptionalUpdate<Addr>>,
+}
+
 #[cw_serde]
 pub struct Job {
   pub initiator: Addr,
   pub incoming: Option<AccountTokenAmount>,
   pub outgoing: Option<AccountTokenAmount>,
+  pub jackpot: Option<String>,
 }
 
 #[cw_serde]
 pub enum ClientMsg {
   Connect(ClientInitArgs),
-  Disconnect { address: Addr },
-  Suspend { address: Addr },
-  Resume { address: Addr },
-  SetConfig { address: Addr, config: ClientConfig },
+  Disconnect {
+    address: Addr,
+  },
+  Suspend {
+    address: Addr,
+  },
+  Resume {
+    address: Addr,
+  },
+  ClaimRevenueShare {
+    address: Addr,
+  },
+  SetConfig {
+    address: Addr,
+    config: ClientConfigUpdates,
+  },
 }
 
 #[cw_serde]
 pub enum PoolMsg {
-  Stake { amount: Uint128 },
+  Stake {
+    amount: Uint128,
+    lockup_seconds: Option<Uint64>,
+    tranche: Option<Tranche>,
+  },
+  StakeFor {
+    beneficiary: Addr,
+    amount: Uint128,
+  },
+  SetRewardRecipient {
+    recipient: Option<Addr>,
+  },
+  FundRewards {
+    token: Token,
+    amount: Uint128,
+    start: Option<Timestamp>,
+    end: Timestamp,
+  },
+  ClaimRewards {
+    token: Token,
+  },
   Claim,
   Unstake,
+  Rebond,
   Withdraw,
 }
 
+#[cw_serde]
+pub enum GovernanceMsg {
+  Propose {
+    title: String,
+    description: Option<String>,
+    actions: Vec<ProposalAction>,
+  },
+  Vote {
+    id: Uint64,
+    vote: Vote,
+  },
+  Execute {
+    id: Uint64,
+  },
+}
+
+#[cw_serde]
+pub enum TimelockMsg {
+  Execute { id: Uint64 },
+  Cancel { id: Uint64 },
+}
+
+#[cw_serde]
+pub enum HookMsg {
+  Add {
+    address: Addr,
+    triggers: Vec<HookTrigger>,
+    loss_threshold: Option<Uint128>,
+    gas_limit: Option<u64>,
+  },
+  Remove {
+    address: Addr,
+  },
+}
+
+/// Payload sent to hook contracts. Hook contracts must handle
+/// `HookExecuteMsg::HouseHook` in their execute entrypoint.
+#[cw_serde]
+pub enum HookPayload {
+  StakingChanged {
+    staker: Addr,
+    action: String,
+    delegation: Uint128,
+    pool_delegation: Uint128,
+  },
+  LargeLoss {
+    client: Addr,
+    initiator: Addr,
+    amount: Uint128,
+  },
+  ClientSuspended {
+    client: Addr,
+    reason: String,
+  },
+  TaxesAccrued {
+    amount: Uint128,
+    total: Uint128,
+  },
+}
+
+#[cw_serde]
+pub enum HookExecuteMsg {
+  HouseHook(HookPayload),
+}
+
 #[cw_serde]
 pub enum CreditMsg {
   Deposit { amount: Uint128 },
   Withdraw { amount: Option<Uint128> },
 }
 
+#[cw_serde]
+pub enum InitiatorMsg {
+  Deny {
+    address: Addr,
+    reason: Option<String>,
+    expires_at: Option<Timestamp>,
+  },
+  Undeny {
+    address: Addr,
+  },
+  Allow {
+    client: Addr,
+    address: Addr,
+    reason: Option<String>,
+    expires_at: Option<Timestamp>,
+  },
+  Disallow {
+    client: Addr,
+    address: Addr,
+  },
+}
+
+#[cw_serde]
+pub enum JackpotMsg {
+  Create {
+    name: String,
+    client: Option<Addr>,
+    contribution_pct: Uint128,
+  },
+  SetContributionPct {
+    name: String,
+    contribution_pct: Uint128,
+  },
+  Remove {
+    name: String,
+  },
+}
+
+#[cw_serde]
+pub enum ReferralMsg {
+  SetReferrer { referrer: Addr },
+  Claim,
+}
+
+#[cw_serde]
+pub enum PlayerMsg {
+  SetLimits { limits: PlayerLimits },
+  CancelPendingLimits,
+  ClaimRakeback { to_bank_account: Option<bool> },
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
   Client(ClientMsg),
   Pool(PoolMsg),
   Credit(CreditMsg),
+  Player(PlayerMsg),
+  Initiators(InitiatorMsg),
+  Referrals(ReferralMsg),
+  Jackpots(JackpotMsg),
+  Governance(GovernanceMsg),
+  Timelock(TimelockMsg),
+  Hooks(HookMsg),
   Process {
     initiator: Addr,
     incoming: Option<AccountTokenAmount>,
     outgoing: Option<AccountTokenAmount>,
+    jackpot: Option<String>,
   },
   ProcessMany(Vec<Job>),
   Receive {
     revenue: Uint128,
   },
   SetConfig {
-    config: Config,
+    config: ConfigUpdates,
   },
-  SetOwner {
+  ProposeOwner {
     owner: Owner,
   },
+  AcceptOwnership,
+  CancelOwnershipTransfer,
   SetTaxes {
     recipients: Vec<TaxRecipient>,
   },
   PayTaxes,
+  Pause {
+    scopes: Vec<PauseScope>,
+  },
+  Unpause {
+    scopes: Option<Vec<PauseScope>>,
+  },
 }
 
 #[cw_serde]
@@ -99,12 +326,62 @@ pub enum QueryMsg {
     fields: Option<Vec<String>>,
     wallet: Option<Addr>,
   },
+  Events {
+    start_after: Option<Uint64>,
+    limit: Option<u8>,
+    client: Option<Addr>,
+    kind: Option<String>,
+  },
+  ClientStats {
+    address: Addr,
+    from: Option<Timestamp>,
+    to: Option<Timestamp>,
+  },
+  Player {
+    address: Addr,
+  },
+  Referrals {
+    referrer: Addr,
+    cursor: Option<Addr>,
+    limit: Option<u8>,
+  },
+  Players {
+    order_by: Option<PlayerStatsOrderBy>,
+    cursor: Option<Addr>,
+    limit: Option<u8>,
+  },
+  Proposal {
+    id: Uint64,
+  },
+  Proposals {
+    cursor: Option<Uint64>,
+    limit: Option<u8>,
+  },
+  VotingPower {
+    address: Addr,
+    height: Option<Uint64>,
+  },
+  StakedBalanceAtHeight {
+    address: Addr,
+    height: Option<u64>,
+  },
+  TotalStakedAtHeight {
+    height: Option<u64>,
+  },
+  StakeAt {
+    address: Addr,
+    height: Option<Uint64>,
+  },
+  TotalStakeAt {
+    height: Option<Uint64>,
+  },
 }
 #[cw_serde]
 pub enum MigrateMsg {
   NoOp {},
   V0_0_4 {},
   V0_0_5 {},
+  V0_0_6 {},
 }
 
 #[cw_serde]
@@ -121,6 +398,7 @@ pub struct AccountView {
   pub stake: Option<StakeAccount>,
   pub bank: Option<BankAccount>,
   pub client: Option<Client>,
+  pub rewards: Vec<RewardAccount>,
   pub is_suspended: bool,
 }
 
@@ -144,6 +422,8 @@ pub struct ClientView {
   pub is_suspended: bool,
   pub revenue: Uint128,
   pub expense: Uint128,
+  pub revenue_share_balance: Uint128,
+  pub revenue_share_claimed: Uint128,
   pub executions: Uint64,
 }
 
@@ -161,13 +441,89 @@ impl ClientView {
       is_suspended: client.is_suspended,
       expense: client.expense,
       revenue: client.revenue,
+      revenue_share_balance: client.revenue_share_balance.unwrap_or_default(),
+      revenue_share_claimed: client.revenue_share_claimed.unwrap_or_default(),
     }
   }
 }
 
+#[cw_serde]
+pub struct OwnerView {
+  pub owner: Owner,
+  pub pending: Option<Owner>,
+}
+
+#[cw_serde]
+pub struct JackpotView {
+  pub jackpot: Jackpot,
+  pub contributions: Vec<JackpotContribution>,
+}
+
+#[cw_serde]
+pub struct ProposalView {
+  pub proposal: Proposal,
+  pub status: ProposalStatus,
+}
+
+#[cw_serde]
+pub struct VotingPowerResponse {
+  pub height: Uint64,
+  pub power: Uint128,
+  pub total_power: Uint128,
+}
+
+#[cw_serde]
+pub struct StakeAtResponse {
+  pub height: Uint64,
+  pub balance: Uint128,
+  pub liquidity: Uint128,
+}
+
+#[cw_serde]
+pub struct TotalStakeAtResponse {
+  pub height: Uint64,
+  pub total: Uint128,
+  pub liquidity: Uint128,
+}
+
+/// Matches cw20-stake's response, where the balance is the staker's delegation.
+#[cw_serde]
+pub struct StakedBalanceAtHeightResponse {
+  pub balance: Uint128,
+  pub height: u64,
+}
+
+/// Matches cw20-stake's response, where the total is the pool's delegation.
+#[cw_serde]
+pub struct TotalStakedAtHeightResponse {
+  pub total: Uint128,
+  pub height: u64,
+}
+
+#[cw_serde]
+pub struct EventsResponse {
+  pub events: Vec<HouseEventRecord>,
+  pub cursor: Option<Uint64>,
+}
+
+#[cw_serde]
+pub struct ReferralsResponse {
+  pub account: Option<ReferralAccount>,
+  pub referrals: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct PlayerView {
+  pub stats: Option<PlayerStats>,
+  pub clients: Vec<PlayerClientStats>,
+  pub limits: Option<PlayerLimits>,
+  pub pending_limits: Option<PendingPlayerLimits>,
+  pub rakeback: Uint128,
+}
+
 #[cw_serde]
 pub struct SelectResponse {
-  pub owner: Option<Owner>,
+  pub owner: Option<OwnerView>,
   pub config: Option<Config>,
   pub clients: Option<Vec<ClientView>>,
   pub pool: Option<Pool>,
@@ -175,12 +531,17 @@ pub struct SelectResponse {
   pub account: Option<AccountView>,
   pub taxes: Option<Vec<TaxRecipient>>,
   pub metadata: Option<Metadata>,
-  pub events: Option<Vec<HouseEvent>>,
+  pub events: Option<Vec<HouseEventRecord>>,
+  pub pending_changes: Option<Vec<PendingChange>>,
+  pub paused: Option<Vec<PauseScope>>,
+  pub hooks: Option<Vec<Hook>>,
+  pub jackpots: Option<Vec<JackpotView>>,
 }
 
 #[cw_serde]
 pub struct CanSpendResponse {
   pub can_spend: bool,
+  pub is_initiator_allowed: bool,
 }
 
 #[cw_serde]
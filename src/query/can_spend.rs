@@ -3,7 +3,7 @@ use cosmwasm_std::{Addr, Deps, Env, Uint128};
 use crate::{
   error::ContractResult,
   msg::CanSpendResponse,
-  state::{is_rate_limited, CONFIG},
+  state::{ensure_initiator_allowed, is_initiator_denied, is_rate_limited, CLIENTS, CONFIG},
   utils::require_valid_address,
 };
 
@@ -40,7 +40,34 @@ pub fn can_spend(
     false
   };
 
+  let is_rate_limited = is_account_rate_limited || is_client_rate_limited;
+
+  // for unknown clients, only rate limits decide whether the spender can
+  // spend, and the initiator is checked against the global denylist alone.
+  let (can_spend, is_initiator_allowed) =
+    match CLIENTS.may_load(deps.storage, client_address.clone())? {
+      Some(client) => {
+        let is_initiator_allowed = ensure_initiator_allowed(
+          deps.storage,
+          env.block.time,
+          &client_address,
+          &client,
+          &spender,
+        )
+        .is_ok();
+        (
+          is_initiator_allowed && !is_rate_limited,
+          is_initiator_allowed,
+        )
+      },
+      None => (
+        !is_rate_limited,
+        !is_initiator_denied(deps.storage, env.block.time, &spender)?,
+      ),
+    };
+
   Ok(CanSpendResponse {
-    can_spend: !(is_account_rate_limited || is_client_rate_limited),
+    can_spend,
+    is_initiator_allowed,
   })
 }
@@ -0,0 +1,28 @@
+use cosmwasm_std::{Addr, Deps, Order, Timestamp};
+use cw_storage_plus::Bound;
+
+use crate::{error::ContractResult, models::ClientStatsBucket, state::CLIENT_STATS};
+
+/// Max number of buckets returned per query.
+const MAX_BUCKETS: usize = 366;
+
+/// Return a client's P&L stats buckets in ascending order of start time,
+/// optionally bounded by an inclusive time range.
+pub fn client_stats(
+  deps: Deps,
+  address: Addr,
+  maybe_from: Option<Timestamp>,
+  maybe_to: Option<Timestamp>,
+) -> ContractResult<Vec<ClientStatsBucket>> {
+  let range_min = maybe_from.map(|t| Bound::inclusive(t.seconds()));
+  let range_max = maybe_to.map(|t| Bound::inclusive(t.seconds()));
+
+  let buckets = CLIENT_STATS
+    .prefix(address)
+    .range(deps.storage, range_min, range_max, Order::Ascending)
+    .take(MAX_BUCKETS)
+    .map(|result| result.unwrap().1)
+    .collect();
+
+  Ok(buckets)
+}
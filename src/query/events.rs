@@ -0,0 +1,62 @@
+use cosmwasm_std::{Addr, Deps, Order, Uint64};
+use cw_storage_plus::Bound;
+
+use crate::{error::ContractResult, msg::EventsResponse, state::EVENT_LOG};
+
+/// Maximum number of records scanned per page, whether or not they match the
+/// filters.
+pub const MAX_EVENTS_SCANNED: usize = 200;
+
+/// Page through the event log in ascending order of sequence number,
+/// optionally filtering by client address and event kind. At most
+/// MAX_EVENTS_SCANNED records are scanned per page, so a page may hold fewer
+/// than the limit even when more matches remain. The returned cursor is the
+/// sequence number of the last record scanned, to pass as start_after for the
+/// next page, and is None once the end of the log is reached.
+pub fn events(
+  deps: Deps,
+  maybe_start_after: Option<Uint64>,
+  maybe_limit: Option<u8>,
+  maybe_client: Option<Addr>,
+  maybe_kind: Option<String>,
+) -> ContractResult<EventsResponse> {
+  let limit = maybe_limit.unwrap_or(20u8) as usize;
+  let range_min = maybe_start_after.map(|seq_no| Bound::exclusive(seq_no.u64()));
+
+  let mut events = vec![];
+  let mut last_seq_no: Option<u64> = None;
+  let mut n_scanned: usize = 0;
+
+  for result in EVENT_LOG
+    .range(deps.storage, range_min, None, Order::Ascending)
+    .take(MAX_EVENTS_SCANNED)
+  {
+    let (seq_no, record) = result?;
+    last_seq_no = Some(seq_no);
+    n_scanned += 1;
+    if let Some(kind) = &maybe_kind {
+      if record.event.kind() != kind.as_str() {
+        continue;
+      }
+    }
+    if let Some(client) = &maybe_client {
+      if record.event.client() != Some(client) {
+        continue;
+      }
+    }
+    events.push(record);
+    if events.len() == limit {
+      break;
+    }
+  }
+
+  // The log is exhausted if the scan stopped short of both the limit and the
+  // scan cap.
+  let cursor = if events.len() < limit && n_scanned < MAX_EVENTS_SCANNED {
+    None
+  } else {
+    last_seq_no.map(Uint64::from)
+  };
+
+  Ok(EventsResponse { events, cursor })
+}
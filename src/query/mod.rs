@@ -1,9 +1,23 @@
 mod accounts;
 mod can_spend;
 mod client;
+mod client_stats;
+mod events;
+mod players;
+mod proposals;
+mod referrals;
 mod select;
+mod stake_at;
+mod staked_at_height;
 
 pub use accounts::accounts;
 pub use can_spend::can_spend;
 pub use client::query_client;
+pub use client_stats::client_stats;
+pub use events::events;
+pub use players::{player, players};
+pub use proposals::{proposal, proposals, voting_power};
+pub use referrals::referrals;
 pub use select::select;
+pub use stake_at::{stake_at, total_stake_at};
+pub use staked_at_height::{staked_balance_at_height, total_staked_at_height};
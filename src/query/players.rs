@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+
+use cosmwasm_std::{Addr, Deps, Env, Order};
+use cw_storage_plus::{Bound, Map};
+
+use crate::{
+  error::ContractResult,
+  models::{PlayerStats, PlayerStatsOrderBy},
+  msg::PlayerView,
+  state::{
+    PENDING_PLAYER_LIMITS, PLAYERS_BY_INCOMING, PLAYERS_BY_OUTGOING, PLAYER_CLIENT_STATS,
+    PLAYER_LIMITS, PLAYER_STATS, RAKEBACK,
+  },
+};
+
+/// Max number of per-client stats returned with a player.
+const MAX_PLAYER_CLIENTS: usize = 50;
+
+pub fn player(
+  deps: Deps,
+  env: Env,
+  address: Addr,
+) -> ContractResult<PlayerView> {
+  let stats = PLAYER_STATS
+    .may_load(deps.storage, address.clone())?
+    .map(|mut stats| {
+      stats.address = Some(address.clone());
+      stats
+    });
+
+  let clients = PLAYER_CLIENT_STATS
+    .prefix(address.clone())
+    .range(deps.storage, None, None, Order::Ascending)
+    .take(MAX_PLAYER_CLIENTS)
+    .map(|result| {
+      let (client_addr, mut stats) = result.unwrap();
+      stats.client = Some(client_addr);
+      stats
+    })
+    .collect();
+
+  // Pending limits whose delay has elapsed are promoted lazily on the next
+  // job, so report them as the player's current limits here.
+  let mut limits = PLAYER_LIMITS.may_load(deps.storage, address.clone())?;
+  let mut pending_limits = PENDING_PLAYER_LIMITS.may_load(deps.storage, address.clone())?;
+  if let Some(pending) = pending_limits.clone() {
+    if env.block.time >= pending.eta {
+      limits = Some(pending.limits);
+      pending_limits = None;
+    }
+  }
+
+  Ok(PlayerView {
+    stats,
+    clients,
+    limits,
+    pending_limits,
+    rakeback: RAKEBACK
+      .may_load(deps.storage, address.clone())?
+      .unwrap_or_default(),
+  })
+}
+
+/// Page through player stats, either by address or as a leaderboard in
+/// descending order of total incoming or outgoing amount. The cursor is the
+/// address of the last player in the previous page.
+pub fn players(
+  deps: Deps,
+  maybe_order_by: Option<PlayerStatsOrderBy>,
+  maybe_cursor: Option<Addr>,
+  maybe_limit: Option<u8>,
+) -> ContractResult<Vec<PlayerStats>> {
+  let limit = maybe_limit.unwrap_or(20u8) as usize;
+
+  let addresses: Vec<Addr> = match maybe_order_by.unwrap_or(PlayerStatsOrderBy::Address) {
+    PlayerStatsOrderBy::Address => {
+      let range_min = maybe_cursor.map(|addr| Bound::Exclusive((addr, PhantomData)));
+      PLAYER_STATS
+        .keys(deps.storage, range_min, None, Order::Ascending)
+        .take(limit)
+        .map(|result| result.unwrap())
+        .collect()
+    },
+    PlayerStatsOrderBy::Incoming => {
+      leaderboard(deps, &PLAYERS_BY_INCOMING, maybe_cursor, limit, |stats| {
+        stats.incoming.u128()
+      })?
+    },
+    PlayerStatsOrderBy::Outgoing => {
+      leaderboard(deps, &PLAYERS_BY_OUTGOING, maybe_cursor, limit, |stats| {
+        stats.outgoing.u128()
+      })?
+    },
+  };
+
+  let mut players: Vec<PlayerStats> = Vec::with_capacity(addresses.len());
+  for addr in addresses.iter() {
+    let mut stats = PLAYER_STATS.load(deps.storage, addr.clone())?;
+    stats.address = Some(addr.clone());
+    players.push(stats);
+  }
+
+  Ok(players)
+}
+
+fn leaderboard(
+  deps: Deps,
+  index: &Map<(u128, Addr), bool>,
+  maybe_cursor: Option<Addr>,
+  limit: usize,
+  get_amount: fn(&PlayerStats) -> u128,
+) -> ContractResult<Vec<Addr>> {
+  let mut range_max = None;
+  if let Some(cursor) = maybe_cursor {
+    if let Some(stats) = PLAYER_STATS.may_load(deps.storage, cursor.clone())? {
+      range_max = Some(Bound::exclusive((get_amount(&stats), cursor)));
+    }
+  }
+  Ok(
+    index
+      .keys(deps.storage, None, range_max, Order::Descending)
+      .take(limit)
+      .map(|result| result.unwrap().1)
+      .collect(),
+  )
+}
@@ -0,0 +1,65 @@
+use cosmwasm_std::{Addr, Deps, Env, Order, Uint64};
+use cw_storage_plus::Bound;
+
+use crate::{
+  error::{ContractError, ContractResult},
+  msg::{ProposalView, VotingPowerResponse},
+  state::{voting_power_at, PROPOSALS},
+};
+
+pub fn proposal(
+  deps: Deps,
+  env: Env,
+  id: Uint64,
+) -> ContractResult<ProposalView> {
+  let proposal = PROPOSALS
+    .may_load(deps.storage, id.u64())?
+    .ok_or(ContractError::ProposalNotFound)?;
+  Ok(ProposalView {
+    status: proposal.status(env.block.time),
+    proposal,
+  })
+}
+
+/// Page through proposals, newest first. The cursor is the ID of the last
+/// proposal in the previous page.
+pub fn proposals(
+  deps: Deps,
+  env: Env,
+  maybe_cursor: Option<Uint64>,
+  maybe_limit: Option<u8>,
+) -> ContractResult<Vec<ProposalView>> {
+  let limit = maybe_limit.unwrap_or(20u8) as usize;
+  let range_max = maybe_cursor.map(|id| Bound::exclusive(id.u64()));
+
+  Ok(
+    PROPOSALS
+      .range(deps.storage, None, range_max, Order::Descending)
+      .take(limit)
+      .map(|result| {
+        let (_, proposal) = result.unwrap();
+        ProposalView {
+          status: proposal.status(env.block.time),
+          proposal,
+        }
+      })
+      .collect(),
+  )
+}
+
+/// Return an address's voting power at the given height, which defaults to
+/// the current block.
+pub fn voting_power(
+  deps: Deps,
+  env: Env,
+  address: Addr,
+  maybe_height: Option<Uint64>,
+) -> ContractResult<VotingPowerResponse> {
+  let height = maybe_height.unwrap_or(env.block.height.into());
+  let (power, total_power) = voting_power_at(deps.storage, &address, height.u64())?;
+  Ok(VotingPowerResponse {
+    height,
+    power,
+    total_power,
+  })
+}
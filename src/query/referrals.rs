@@ -0,0 +1,38 @@
+use std::marker::PhantomData;
+
+use cosmwasm_std::{Addr, Deps, Order};
+use cw_storage_plus::Bound;
+
+use crate::{
+  error::ContractResult,
+  msg::ReferralsResponse,
+  state::{REFERRALS, REFERRAL_ACCOUNTS},
+};
+
+/// Return an affiliate's referral account along with a page of the initiators
+/// they referred, in ascending order of address.
+pub fn referrals(
+  deps: Deps,
+  referrer: Addr,
+  maybe_cursor: Option<Addr>,
+  maybe_limit: Option<u8>,
+) -> ContractResult<ReferralsResponse> {
+  let limit = maybe_limit.unwrap_or(20u8) as usize;
+  let range_min = maybe_cursor.map(|addr| Bound::Exclusive((addr, PhantomData)));
+
+  let account = REFERRAL_ACCOUNTS
+    .may_load(deps.storage, referrer.clone())?
+    .map(|mut account| {
+      account.address = Some(referrer.clone());
+      account
+    });
+
+  let referrals = REFERRALS
+    .prefix(referrer)
+    .keys(deps.storage, range_min, None, Order::Ascending)
+    .take(limit)
+    .map(|result| result.unwrap())
+    .collect();
+
+  Ok(ReferralsResponse { account, referrals })
+}
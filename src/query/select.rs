@@ -1,10 +1,13 @@
 use crate::{
   error::ContractResult,
-  msg::{AccountView, ClientView, Metadata, SelectResponse, Totals},
+  models::JackpotContribution,
+  msg::{AccountView, ClientView, JackpotView, Metadata, OwnerView, SelectResponse, Totals},
   state::{
-    is_rate_limited, sync_account_readonly, BANK_ACCOUNTS, CLIENTS, CLIENT_EXECUTION_COUNTS,
-    CONFIG, EVENTS, LEDGER_ENTRY_SEQ_NO, N_CLIENTS, N_LEDGER_ENTRIES, N_STAKE_ACCOUNTS,
-    N_STAKE_ACCOUNTS_UNBONDING, OWNER, POOL, STAKE_ACCOUNTS, TAX_RECIPIENTS, TOTAL_STREAM_REVENUE,
+    is_rate_limited, reward_accounts_readonly, sync_account_readonly, BANK_ACCOUNTS, CLIENTS,
+    CLIENT_EXECUTION_COUNTS, CONFIG, EVENT_LOG, HOOKS, JACKPOTS, JACKPOT_CONTRIBUTIONS,
+    JACKPOT_PAYOUTS, LEDGER_ENTRY_SEQ_NO, N_CLIENTS, N_LEDGER_ENTRIES, N_STAKE_ACCOUNTS,
+    N_STAKE_ACCOUNTS_UNBONDING, OWNER, PAUSED_SCOPES, PENDING_CHANGES, PENDING_OWNER, POOL,
+    STAKE_ACCOUNTS, TAX_RECIPIENTS, TOTAL_STREAM_REVENUE,
   },
 };
 use cosmwasm_std::{Addr, Deps, Env, Order, Uint128};
@@ -20,7 +23,12 @@ pub fn select(
   let config = CONFIG.load(deps.storage)?;
 
   Ok(SelectResponse {
-    owner: loader.get("owner", &OWNER)?,
+    owner: loader.view("owner", || {
+      Ok(Some(OwnerView {
+        owner: OWNER.load(deps.storage)?,
+        pending: PENDING_OWNER.may_load(deps.storage)?,
+      }))
+    })?,
 
     // house configuration settings
     config: loader.view("config", || Ok(Some(config.clone())))?,
@@ -56,12 +64,13 @@ pub fn select(
       Ok(Some(Totals { revenue, expense }))
     })?,
 
+    // latest entries in the event log, newest first
     events: loader.view("events", || {
       Ok(Some(
-        EVENTS
-          .iter(deps.storage)?
+        EVENT_LOG
+          .range(deps.storage, None, None, Order::Descending)
           .take(20)
-          .map(|x| x.unwrap())
+          .map(|x| x.unwrap().1)
           .collect(),
       ))
     })?,
@@ -96,6 +105,64 @@ pub fn select(
       ))
     })?,
 
+    // contracts subscribed to house activity
+    hooks: loader.view("hooks", || {
+      Ok(Some(
+        HOOKS
+          .range(deps.storage, None, None, Order::Ascending)
+          .map(|r| {
+            let (addr, mut hook) = r.unwrap();
+            hook.address = Some(addr);
+            hook
+          })
+          .collect(),
+      ))
+    })?,
+
+    // jackpot sub-pools and each client's contributions to them
+    jackpots: loader.view("jackpots", || {
+      Ok(Some(
+        JACKPOTS
+          .range(deps.storage, None, None, Order::Ascending)
+          .map(|r| {
+            let (name, jackpot) = r.unwrap();
+            JackpotView {
+              jackpot,
+              contributions: JACKPOT_CONTRIBUTIONS
+                .prefix(name.clone())
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|r| {
+                  let (client, amount) = r.unwrap();
+                  let paid_out = JACKPOT_PAYOUTS
+                    .may_load(deps.storage, (name.clone(), client.clone()))
+                    .unwrap()
+                    .unwrap_or_default();
+                  JackpotContribution {
+                    client,
+                    amount,
+                    paid_out,
+                  }
+                })
+                .collect(),
+            }
+          })
+          .collect(),
+      ))
+    })?,
+
+    // scopes of the house that are currently paused
+    paused: loader.get("paused", &PAUSED_SCOPES)?,
+
+    // sensitive changes queued behind the timelock
+    pending_changes: loader.view("pending_changes", || {
+      Ok(Some(
+        PENDING_CHANGES
+          .range(deps.storage, None, None, Order::Ascending)
+          .map(|r| r.unwrap().1)
+          .collect(),
+      ))
+    })?,
+
     // client contracts connected to the house
     clients: loader.view("clients", || {
       Ok(Some(
@@ -125,8 +192,18 @@ pub fn select(
       )
       .unwrap_or(false);
 
+      let rewards = reward_accounts_readonly(
+        deps.storage,
+        env.block.time,
+        &wallet,
+        maybe_stake_account
+          .as_ref()
+          .map(|a| a.delegation)
+          .unwrap_or_default(),
+      )?;
+
       maybe_stake_account = if let Some(mut stake_account) = maybe_stake_account {
-        if stake_account.unbonding.is_none() {
+        if stake_account.unbonding.is_none() || stake_account.is_unbonding_at_risk() {
           sync_account_readonly(deps.storage, deps.api, &mut stake_account, true).unwrap();
         }
         Some(stake_account)
@@ -138,6 +215,7 @@ pub fn select(
         bank: maybe_bank_account,
         stake: maybe_stake_account,
         client: CLIENTS.may_load(deps.storage, wallet.clone())?,
+        rewards,
         is_suspended,
       }))
     })?,
@@ -0,0 +1,43 @@
+use cosmwasm_std::{Addr, Deps, Env, Uint128, Uint64};
+
+use crate::{
+  error::ContractResult,
+  msg::{StakeAtResponse, TotalStakeAtResponse},
+  state::{stake_at as load_stake_at, POOL_SNAPSHOTS},
+};
+
+/// Return a staker's delegation and liquidity as of the start of the block at
+/// the given height, defaulting to the current block.
+pub fn stake_at(
+  deps: Deps,
+  env: Env,
+  address: Addr,
+  maybe_height: Option<Uint64>,
+) -> ContractResult<StakeAtResponse> {
+  let height = maybe_height.unwrap_or(env.block.height.into());
+  let (stake, _) = load_stake_at(deps.storage, &address, height.u64())?;
+  Ok(StakeAtResponse {
+    height,
+    balance: stake.delegation,
+    liquidity: stake.liquidity,
+  })
+}
+
+/// Return the pool's total delegation and liquidity as of the start of the
+/// block at the given height, defaulting to the current block.
+pub fn total_stake_at(
+  deps: Deps,
+  env: Env,
+  maybe_height: Option<Uint64>,
+) -> ContractResult<TotalStakeAtResponse> {
+  let height = maybe_height.unwrap_or(env.block.height.into());
+  let (total, liquidity) = POOL_SNAPSHOTS
+    .may_load_at_height(deps.storage, height.u64())?
+    .map(|snapshot| (snapshot.delegation, snapshot.liquidity))
+    .unwrap_or((Uint128::zero(), Uint128::zero()));
+  Ok(TotalStakeAtResponse {
+    height,
+    total,
+    liquidity,
+  })
+}
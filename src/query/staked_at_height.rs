@@ -0,0 +1,42 @@
+use cosmwasm_std::{Addr, Deps, Env};
+
+use crate::{
+  error::ContractResult,
+  msg::{StakedBalanceAtHeightResponse, TotalStakedAtHeightResponse},
+  state::{POOL_SNAPSHOTS, STAKE_SNAPSHOTS},
+};
+
+/// Return a staker's delegation as of the start of the block at the given
+/// height, defaulting to the current block.
+pub fn staked_balance_at_height(
+  deps: Deps,
+  env: Env,
+  address: Addr,
+  maybe_height: Option<u64>,
+) -> ContractResult<StakedBalanceAtHeightResponse> {
+  let height = maybe_height.unwrap_or(env.block.height);
+  Ok(StakedBalanceAtHeightResponse {
+    balance: STAKE_SNAPSHOTS
+      .may_load_at_height(deps.storage, address, height)?
+      .map(|snapshot| snapshot.delegation)
+      .unwrap_or_default(),
+    height,
+  })
+}
+
+/// Return the pool's total delegation as of the start of the block at the
+/// given height, defaulting to the current block.
+pub fn total_staked_at_height(
+  deps: Deps,
+  env: Env,
+  maybe_height: Option<u64>,
+) -> ContractResult<TotalStakedAtHeightResponse> {
+  let height = maybe_height.unwrap_or(env.block.height);
+  Ok(TotalStakedAtHeightResponse {
+    total: POOL_SNAPSHOTS
+      .may_load_at_height(deps.storage, height)?
+      .map(|snapshot| snapshot.delegation)
+      .unwrap_or_default(),
+    height,
+  })
+}
@@ -0,0 +1,16 @@
+use crate::error::ContractResult;
+use cosmwasm_std::{attr, DepsMut, Env, Reply, Response, SubMsgResult};
+
+/// Swallow the error returned by a failed hook, so that the house's own state
+/// changes are committed regardless.
+pub fn hook(
+  _deps: DepsMut,
+  _env: Env,
+  reply: Reply,
+) -> ContractResult<Response> {
+  let mut resp = Response::new().add_attributes(vec![attr("action", "hook_failed")]);
+  if let SubMsgResult::Err(error) = reply.result {
+    resp = resp.add_attribute("error", error);
+  }
+  Ok(resp)
+}
@@ -0,0 +1,3 @@
+mod hook;
+
+pub use hook::hook;
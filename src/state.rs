@@ -1,21 +1,46 @@
 use crate::error::{ContractError, ContractResult};
 use crate::models::{
-  BankAccount, Client, Config, HouseEvent, LedgerEntry, LedgerUpdates, LiquidityUsage, Pool,
-  RateLimitConfig, RevenueStream, StakeAccount, TaxRecipient, Usage,
+  Ballot, BankAccount, Client, ClientStatsBucket, Config, Hook, HookTrigger, HouseEvent,
+  HouseEventRecord, InitiatorListEntry, Jackpot, LedgerEntry, LedgerUpdates, LiquidityUsage,
+  PauseScope, PendingChange, PendingPlayerLimits, PeriodLimit, PeriodUsage, PlayerClientStats,
+  PlayerLimits, PlayerStats, Pool, Proposal, RateLimitConfig, ReferralAccount, RevenueStream,
+  RewardAccount, RewardStream, RewardToken, StakeAccount, StakeSnapshot, TaxRecipient,
+  TimelockAction, Tranche, TrancheLedgerEntry, TrancheTotals, Usage,
 };
-use crate::msg::InstantiateMsg;
-use crate::utils::{decrement, increment, mul_pct};
+use crate::msg::{ConfigUpdates, HookExecuteMsg, HookPayload, InstantiateMsg};
+use crate::utils::{decrement, increment, mul_pct, PCT_DENOMINATOR};
 use cosmwasm_std::{
-  Addr, Api, BlockInfo, Coin, Deps, DepsMut, Env, MessageInfo, Storage, Uint128, Uint64,
+  to_binary, Addr, Api, Attribute, BlockInfo, Coin, Deps, DepsMut, Env, MessageInfo, Order,
+  ReplyOn, StdError, StdResult, Storage, SubMsg, Timestamp, Uint128, Uint256, Uint64, WasmMsg,
 };
 use cw_acl::client::Acl;
-use cw_lib::models::Owner;
+use cw_lib::models::{Owner, Token};
 use cw_lib::utils::funds::has_funds;
-use cw_storage_plus::{Deque, Item, Map};
+use cw_storage_plus::{Bound, Deque, Item, Map, SnapshotItem, SnapshotMap, Strategy};
 
-pub const MAX_EVENT_QUEUE_SIZE: u32 = 100;
+pub const DEFAULT_MAX_EVENT_LOG_SIZE: u32 = 100;
+pub const MAX_EVENT_LOG_SIZE: u32 = 10_000;
+/// Max number of events evicted per logged event. After the max log size is
+/// lowered, the backlog drains over subsequent calls instead of all at once.
+pub const MAX_EVENT_LOG_EVICTIONS: u32 = 10;
+pub const MAX_HOOKS: usize = 10;
+pub const HOOK_REPLY_ID: u64 = 1;
+/// Gas limit of hooks added without one. Without a limit, a hook that runs out
+/// of gas would abort the whole transaction instead of replying with an error.
+pub const DEFAULT_HOOK_GAS_LIMIT: u64 = 500_000;
+pub const MAX_JACKPOTS: usize = 10;
+pub const MAX_REWARD_TOKENS: usize = 10;
+pub const MAX_REWARD_STREAMS: usize = 20;
+pub const REWARD_INDEX_SCALE: u128 = 1_000_000_000_000;
+pub const DEFAULT_JUNIOR_REVENUE_MULTIPLIER: u128 = 1_500_000;
+pub const DEFAULT_STATS_BUCKET_SECONDS: u64 = 60 * 60 * 24;
+pub const DEFAULT_STATS_RETENTION_BUCKETS: u32 = 90;
+/// Max number of stale stats records pruned per job.
+pub const MAX_STATS_PRUNED_PER_JOB: usize = 10;
+pub const DEFAULT_PLAYER_LIMITS_DELAY_SECONDS: u64 = 60 * 60 * 24;
 
 pub const OWNER: Item<Owner> = Item::new("owner");
+pub const PENDING_OWNER: Item<Owner> = Item::new("pending_owner");
 pub const POOL: Item<Pool> = Item::new("pool");
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const STAKE_ACCOUNTS: Map<Addr, StakeAccount> = Map::new("stake_accounts");
@@ -25,17 +50,70 @@ pub const LEDGER_ENTRY_SEQ_NO: Item<Uint128> = Item::new("ledger_entry_seq_no");
 pub const N_LEDGER_ENTRIES: Item<u32> = Item::new("n_ledger_entries");
 pub const N_STAKE_ACCOUNTS: Item<u32> = Item::new("n_stake_accounts");
 pub const N_STAKE_ACCOUNTS_UNBONDING: Item<u32> = Item::new("n_stake_accounts_unbonding");
+pub const N_STAKE_ACCOUNTS_UNBONDING_AT_RISK: Item<u32> =
+  Item::new("n_stake_accounts_unbonding_at_risk");
 pub const N_DELEGATION_MUTATIONS: Item<Uint128> = Item::new("n_delegation_mutations");
 pub const N_CLIENTS: Item<u32> = Item::new("n_clients");
 pub const TAX_RECIPIENTS: Map<Addr, TaxRecipient> = Map::new("tax_recipients");
 pub const LIQUIDITY_USAGE: Map<Addr, LiquidityUsage> = Map::new("liquidity_usage");
 pub const USAGE: Map<Addr, Usage> = Map::new("usage");
 pub const MEMOIZATION_QUEUE: Deque<Addr> = Deque::new("memoization_queue");
+pub const MEMOIZATION_QUEUE_MEMBERS: Map<Addr, bool> = Map::new("memoization_queue_members");
+// Legacy event queue, replaced by EVENT_LOG as of v0.0.6.
 pub const EVENTS: Deque<HouseEvent> = Deque::new("events");
+pub const EVENT_LOG: Map<u64, HouseEventRecord> = Map::new("event_log");
+pub const EVENT_LOG_SIZE: Item<u32> = Item::new("event_log_size");
+pub const EVENT_SEQ_NO: Item<Uint64> = Item::new("event_seq_no");
 pub const CLIENTS: Map<Addr, Client> = Map::new("clients");
 pub const CLIENT_EXECUTION_COUNTS: Map<Addr, Uint64> = Map::new("client_execution_counts");
 pub const STREAMS: Map<Addr, RevenueStream> = Map::new("revenue_streams");
 pub const TOTAL_STREAM_REVENUE: Item<Uint128> = Item::new("total_stream_revenue");
+pub const PENDING_CHANGES: Map<u64, PendingChange> = Map::new("pending_changes");
+pub const PENDING_CHANGE_SEQ_NO: Item<Uint64> = Item::new("pending_change_seq_no");
+pub const PAUSED_SCOPES: Item<Vec<PauseScope>> = Item::new("paused_scopes");
+pub const HOOKS: Map<Addr, Hook> = Map::new("hooks");
+pub const CLIENT_STATS: Map<(Addr, u64), ClientStatsBucket> = Map::new("client_stats");
+pub const PLAYER_STATS: Map<Addr, PlayerStats> = Map::new("player_stats");
+pub const PLAYER_CLIENT_STATS: Map<(Addr, Addr), PlayerClientStats> =
+  Map::new("player_client_stats");
+pub const PLAYERS_BY_INCOMING: Map<(u128, Addr), bool> = Map::new("players_by_incoming");
+pub const PLAYERS_BY_OUTGOING: Map<(u128, Addr), bool> = Map::new("players_by_outgoing");
+pub const PLAYER_LIMITS: Map<Addr, PlayerLimits> = Map::new("player_limits");
+pub const PENDING_PLAYER_LIMITS: Map<Addr, PendingPlayerLimits> = Map::new("pending_player_limits");
+pub const PLAYER_LOSS_USAGE: Map<Addr, PeriodUsage> = Map::new("player_loss_usage");
+pub const PLAYER_WAGER_USAGE: Map<Addr, PeriodUsage> = Map::new("player_wager_usage");
+pub const DENIED_INITIATORS: Map<Addr, InitiatorListEntry> = Map::new("denied_initiators");
+pub const ALLOWED_INITIATORS: Map<(Addr, Addr), InitiatorListEntry> =
+  Map::new("allowed_initiators");
+pub const RAKEBACK: Map<Addr, Uint128> = Map::new("rakeback");
+pub const RAKEBACK_TOTAL: Item<Uint128> = Item::new("rakeback_total");
+pub const REFERRERS: Map<Addr, Addr> = Map::new("referrers");
+pub const REFERRALS: Map<(Addr, Addr), Timestamp> = Map::new("referrals");
+pub const REFERRAL_ACCOUNTS: Map<Addr, ReferralAccount> = Map::new("referral_accounts");
+pub const JACKPOTS: Map<String, Jackpot> = Map::new("jackpots");
+pub const JACKPOT_CONTRIBUTIONS: Map<(String, Addr), Uint128> = Map::new("jackpot_contributions");
+pub const JACKPOT_PAYOUTS: Map<(String, Addr), Uint128> = Map::new("jackpot_payouts");
+pub const REWARD_TOKENS: Map<String, RewardToken> = Map::new("reward_tokens");
+pub const REWARD_STREAMS: Map<u64, RewardStream> = Map::new("reward_streams");
+pub const REWARD_STREAM_SEQ_NO: Item<u64> = Item::new("reward_stream_seq_no");
+pub const REWARD_ACCOUNTS: Map<(String, Addr), RewardAccount> = Map::new("reward_accounts");
+pub const STAKE_SNAPSHOTS: SnapshotMap<Addr, StakeSnapshot> = SnapshotMap::new(
+  "stake_snapshots",
+  "stake_snapshots__checkpoints",
+  "stake_snapshots__changelog",
+  Strategy::EveryBlock,
+);
+pub const POOL_SNAPSHOTS: SnapshotItem<StakeSnapshot> = SnapshotItem::new(
+  "pool_snapshots",
+  "pool_snapshots__checkpoints",
+  "pool_snapshots__changelog",
+  Strategy::EveryBlock,
+);
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+pub const PROPOSAL_SEQ_NO: Item<u64> = Item::new("proposal_seq_no");
+pub const BALLOTS: Map<(u64, Addr), Ballot> = Map::new("ballots");
+pub const CLIENT_BUCKET_INITIATORS: Map<(Addr, u64, Addr), bool> =
+  Map::new("client_bucket_initiators");
 
 /// Init contract state.
 pub fn initialize(
@@ -44,33 +122,84 @@ pub fn initialize(
   info: &MessageInfo,
   msg: &InstantiateMsg,
 ) -> ContractResult<()> {
-  // TODO: validate
-  OWNER.save(
-    deps.storage,
-    &msg
-      .owner
-      .clone()
-      .unwrap_or_else(|| Owner::Address(info.sender.clone())),
-  )?;
+  let owner = msg
+    .owner
+    .clone()
+    .unwrap_or_else(|| Owner::Address(info.sender.clone()));
+
+  validate_owner(deps.api, &owner)?;
+  msg.config.validate()?;
+  if let Some(acl) = &msg.config.staker_acl {
+    validate_address(deps.api, acl)?;
+  }
+
+  OWNER.save(deps.storage, &owner)?;
   POOL.save(deps.storage, &Pool::new(&msg.token))?;
   CONFIG.save(deps.storage, &msg.config)?;
   LEDGER_ENTRY_SEQ_NO.save(deps.storage, &Uint128::zero())?;
   N_STAKE_ACCOUNTS.save(deps.storage, &0)?;
   N_STAKE_ACCOUNTS_UNBONDING.save(deps.storage, &0)?;
+  N_STAKE_ACCOUNTS_UNBONDING_AT_RISK.save(deps.storage, &0)?;
   N_CLIENTS.save(deps.storage, &0)?;
   N_LEDGER_ENTRIES.save(deps.storage, &0)?;
   N_DELEGATION_MUTATIONS.save(deps.storage, &Uint128::zero())?;
   TOTAL_STREAM_REVENUE.save(deps.storage, &Uint128::zero())?;
+  PENDING_CHANGE_SEQ_NO.save(deps.storage, &Uint64::zero())?;
+  PAUSED_SCOPES.save(deps.storage, &vec![])?;
+  EVENT_LOG_SIZE.save(deps.storage, &0)?;
+  EVENT_SEQ_NO.save(deps.storage, &Uint64::zero())?;
+  RAKEBACK_TOTAL.save(deps.storage, &Uint128::zero())?;
+  REWARD_STREAM_SEQ_NO.save(deps.storage, &0)?;
+  PROPOSAL_SEQ_NO.save(deps.storage, &0)?;
   if let Some(recipients) = &msg.taxes {
-    insert_tax_recipients(deps.storage, recipients)?;
+    insert_tax_recipients(deps.storage, deps.api, recipients)?;
+  }
+  Ok(())
+}
+
+/// Return error if the owner's address, or the address of its ACL contract, is
+/// invalid.
+pub fn validate_owner(
+  api: &dyn Api,
+  owner: &Owner,
+) -> ContractResult<()> {
+  match owner {
+    Owner::Address(addr) => validate_address(api, addr)?,
+    Owner::Acl(acl_addr) => validate_address(api, acl_addr)?,
+  };
+  Ok(())
+}
+
+/// Return error if any recipient is invalid, if a recipient address appears
+/// more than once, or if the recipients' pcts add up to more than 100%.
+pub fn validate_tax_recipients(
+  api: &dyn Api,
+  recipients: &Vec<TaxRecipient>,
+) -> ContractResult<()> {
+  let mut total_pct = Uint128::zero();
+  let mut addresses: Vec<&Addr> = Vec::with_capacity(recipients.len());
+  for recipient in recipients.iter() {
+    recipient.validate(api)?;
+    if let Some(addr) = &recipient.address {
+      if addresses.contains(&addr) {
+        return Err(ContractError::DuplicateTaxRecipient);
+      }
+      addresses.push(addr);
+    }
+    total_pct += recipient.pct;
+  }
+  if total_pct > Uint128::from(PCT_DENOMINATOR) {
+    return Err(ContractError::InvalidTaxRecipientTotal);
   }
   Ok(())
 }
 
 pub fn insert_tax_recipients(
   storage: &mut dyn Storage,
+  api: &dyn Api,
   recipients: &Vec<TaxRecipient>,
 ) -> ContractResult<()> {
+  validate_tax_recipients(api, recipients)?;
   for recipient in recipients.clone().iter_mut() {
     if let Some(addr) = recipient.address.clone() {
       recipient.address = None;
@@ -80,6 +209,477 @@ pub fn insert_tax_recipients(
   Ok(())
 }
 
+/// Clear existing tax recipients, replacing them with the given ones.
+pub fn replace_tax_recipients(
+  storage: &mut dyn Storage,
+  api: &dyn Api,
+  recipients: &Vec<TaxRecipient>,
+) -> ContractResult<()> {
+  TAX_RECIPIENTS.clear(storage);
+  insert_tax_recipients(storage, api, recipients)
+}
+
+/// Apply the given updates to the house config, returning attributes with the
+/// before and after value of each changed field.
+pub fn update_config(
+  storage: &mut dyn Storage,
+  updates: &ConfigUpdates,
+) -> ContractResult<Vec<Attribute>> {
+  let mut config = CONFIG.load(storage)?;
+  let attrs = config.apply_updates(updates);
+  config.validate()?;
+  CONFIG.save(storage, &config)?;
+  Ok(attrs)
+}
+
+/// Queue the given action if the house has a timelock configured, returning
+/// the queued PendingChange. Otherwise, return None, in which case the caller
+/// should apply the action immediately.
+pub fn enqueue_change(
+  storage: &mut dyn Storage,
+  env: &Env,
+  proposer: &Addr,
+  action: TimelockAction,
+) -> ContractResult<Option<PendingChange>> {
+  let config = CONFIG.load(storage)?;
+  let delay = config.timelock_seconds.unwrap_or_default();
+  if delay.is_zero() {
+    return Ok(None);
+  }
+  let id = increment(storage, &PENDING_CHANGE_SEQ_NO, Uint64::one())?;
+  let change = PendingChange {
+    id,
+    action,
+    proposer: proposer.clone(),
+    created_at: env.block.time,
+    eta: env.block.time.plus_seconds(delay.u64()),
+  };
+  PENDING_CHANGES.save(storage, id.u64(), &change)?;
+  Ok(Some(change))
+}
+
+/// Append an event to the event log, evicting the oldest events if the log has
+/// grown beyond its configured maximum size.
+pub fn log_event(
+  storage: &mut dyn Storage,
+  event: HouseEvent,
+) -> ContractResult<Uint64> {
+  let max_size = CONFIG
+    .load(storage)?
+    .max_event_log_size
+    .unwrap_or(DEFAULT_MAX_EVENT_LOG_SIZE);
+
+  let seq_no = increment(storage, &EVENT_SEQ_NO, Uint64::one())?;
+
+  EVENT_LOG.save(storage, seq_no.u64(), &HouseEventRecord { seq_no, event })?;
+
+  let mut size = increment(storage, &EVENT_LOG_SIZE, 1)?;
+
+  if size > max_size {
+    let stale_seq_nos = EVENT_LOG
+      .keys(storage, None, None, Order::Ascending)
+      .take((size - max_size).min(MAX_EVENT_LOG_EVICTIONS) as usize)
+      .collect::<StdResult<Vec<u64>>>()?;
+    for stale_seq_no in stale_seq_nos.iter() {
+      EVENT_LOG.remove(storage, *stale_seq_no);
+    }
+    size -= stale_seq_nos.len() as u32;
+    EVENT_LOG_SIZE.save(storage, &size)?;
+  }
+
+  Ok(seq_no)
+}
+
+/// Build a fire-and-forget SubMsg for each hook subscribed to the given
+/// payload. Errors in hook contracts are swallowed by the reply entrypoint, so
+/// a broken hook can't block the house.
+pub fn build_hook_submsgs(
+  storage: &dyn Storage,
+  payload: &HookPayload,
+) -> ContractResult<Vec<SubMsg>> {
+  let trigger = match payload {
+    HookPayload::StakingChanged { .. } => HookTrigger::StakingChanged,
+    HookPayload::LargeLoss { .. } => HookTrigger::LargeLoss,
+    HookPayload::ClientSuspended { .. } => HookTrigger::ClientSuspended,
+    HookPayload::TaxesAccrued { .. } => HookTrigger::TaxesAccrued,
+  };
+  let mut submsgs: Vec<SubMsg> = vec![];
+  for result in HOOKS.range(storage, None, None, Order::Ascending) {
+    let (addr, hook) = result?;
+    if !hook.triggers.contains(&trigger) {
+      continue;
+    }
+    if let HookPayload::LargeLoss { amount, .. } = payload {
+      if *amount < hook.loss_threshold.unwrap_or_default() {
+        continue;
+      }
+    }
+    submsgs.push(SubMsg {
+      id: HOOK_REPLY_ID,
+      msg: WasmMsg::Execute {
+        contract_addr: addr.into(),
+        msg: to_binary(&HookExecuteMsg::HouseHook(payload.clone()))?,
+        funds: vec![],
+      }
+      .into(),
+      gas_limit: Some(hook.gas_limit.unwrap_or(DEFAULT_HOOK_GAS_LIMIT)),
+      reply_on: ReplyOn::Error,
+    });
+  }
+  Ok(submsgs)
+}
+
+/// Return the start time, in seconds, of the stats bucket containing the given
+/// block time.
+pub fn get_stats_bucket_start(
+  config: &Config,
+  block: &BlockInfo,
+) -> u64 {
+  let bucket_seconds = config
+    .stats_bucket_seconds
+    .map(|x| x.u64())
+    .unwrap_or(DEFAULT_STATS_BUCKET_SECONDS);
+  let time = block.time.seconds();
+  time - (time % bucket_seconds)
+}
+
+/// Add a processed job to the client's P&L stats bucket for the current block,
+/// pruning buckets that have fallen out of the retention window.
+pub fn record_client_stats(
+  storage: &mut dyn Storage,
+  block: &BlockInfo,
+  config: &Config,
+  client_addr: &Addr,
+  initiator: &Addr,
+  revenue: Uint128,
+  expense: Uint128,
+) -> ContractResult<()> {
+  let bucket_start = get_stats_bucket_start(config, block);
+  let key = (client_addr.clone(), bucket_start);
+
+  let bucket_seconds = config
+    .stats_bucket_seconds
+    .map(|x| x.u64())
+    .unwrap_or(DEFAULT_STATS_BUCKET_SECONDS);
+  let n_buckets = config
+    .stats_retention_buckets
+    .unwrap_or(DEFAULT_STATS_RETENTION_BUCKETS) as u64;
+  let cutoff = bucket_start.saturating_sub(bucket_seconds.saturating_mul(n_buckets));
+
+  // Prune some of the initiators recorded in stale buckets. Every job records
+  // at most one, so this keeps up with the retention window.
+  let stale_initiator_keys = CLIENT_BUCKET_INITIATORS
+    .sub_prefix(client_addr.clone())
+    .keys(
+      storage,
+      None,
+      Some(Bound::exclusive((cutoff, Addr::unchecked("")))),
+      Order::Ascending,
+    )
+    .take(MAX_STATS_PRUNED_PER_JOB)
+    .collect::<StdResult<Vec<(u64, Addr)>>>()?;
+  for (stale_bucket_start, stale_initiator) in stale_initiator_keys.into_iter() {
+    CLIENT_BUCKET_INITIATORS.remove(
+      storage,
+      (client_addr.clone(), stale_bucket_start, stale_initiator),
+    );
+  }
+
+  let mut bucket = if let Some(bucket) = CLIENT_STATS.may_load(storage, key.clone())? {
+    bucket
+  } else {
+    // This is the first job in a new bucket, so prune stale buckets.
+    let stale_bucket_starts = CLIENT_STATS
+      .prefix(client_addr.clone())
+      .keys(
+        storage,
+        None,
+        Some(Bound::exclusive(cutoff)),
+        Order::Ascending,
+      )
+      .take(MAX_STATS_PRUNED_PER_JOB)
+      .collect::<StdResult<Vec<u64>>>()?;
+    for stale_bucket_start in stale_bucket_starts.iter() {
+      CLIENT_STATS.remove(storage, (client_addr.clone(), *stale_bucket_start));
+    }
+    ClientStatsBucket {
+      start: Timestamp::from_seconds(bucket_start),
+      revenue: Uint128::zero(),
+      expense: Uint128::zero(),
+      n_jobs: 0,
+      n_initiators: 0,
+      max_payout: Uint128::zero(),
+    }
+  };
+
+  // Count the initiator once per bucket.
+  let initiator_key = (client_addr.clone(), bucket_start, initiator.clone());
+  if !CLIENT_BUCKET_INITIATORS.has(storage, initiator_key.clone()) {
+    CLIENT_BUCKET_INITIATORS.save(storage, initiator_key, &true)?;
+    bucket.n_initiators += 1;
+  }
+
+  bucket.n_jobs += 1;
+  bucket.revenue += revenue;
+  bucket.expense += expense;
+  if expense > bucket.max_payout {
+    bucket.max_payout = expense;
+  }
+
+  CLIENT_STATS.save(storage, key, &bucket)?;
+  Ok(())
+}
+
+/// Add a processed job to the initiator's lifetime player stats, if enabled,
+/// and keep the leaderboard indices in sync.
+pub fn record_player_stats(
+  storage: &mut dyn Storage,
+  block: &BlockInfo,
+  config: &Config,
+  client_addr: &Addr,
+  initiator: &Addr,
+  incoming: Uint128,
+  outgoing: Uint128,
+) -> ContractResult<()> {
+  if !config.player_stats_enabled.unwrap_or(false) {
+    return Ok(());
+  }
+
+  let mut stats = PLAYER_STATS
+    .may_load(storage, initiator.clone())?
+    .unwrap_or_else(|| PlayerStats {
+      address: None,
+      incoming: Uint128::zero(),
+      outgoing: Uint128::zero(),
+      n_jobs: 0,
+      first_seen: block.time,
+      last_seen: block.time,
+    });
+
+  // Remove the initiator's stale leaderboard entries
+  PLAYERS_BY_INCOMING.remove(storage, (stats.incoming.u128(), initiator.clone()));
+  PLAYERS_BY_OUTGOING.remove(storage, (stats.outgoing.u128(), initiator.clone()));
+
+  stats.incoming += incoming;
+  stats.outgoing += outgoing;
+  stats.n_jobs += 1;
+  stats.last_seen = block.time;
+
+  PLAYERS_BY_INCOMING.save(storage, (stats.incoming.u128(), initiator.clone()), &true)?;
+  PLAYERS_BY_OUTGOING.save(storage, (stats.outgoing.u128(), initiator.clone()), &true)?;
+  PLAYER_STATS.save(storage, initiator.clone(), &stats)?;
+
+  PLAYER_CLIENT_STATS.update(
+    storage,
+    (initiator.clone(), client_addr.clone()),
+    |maybe_stats| -> ContractResult<_> {
+      let mut stats = maybe_stats.unwrap_or_else(|| PlayerClientStats {
+        client: None,
+        incoming: Uint128::zero(),
+        outgoing: Uint128::zero(),
+        n_jobs: 0,
+      });
+      stats.incoming += incoming;
+      stats.outgoing += outgoing;
+      stats.n_jobs += 1;
+      Ok(stats)
+    },
+  )?;
+
+  Ok(())
+}
+
+/// Return true if the initiator is on the global denylist and the entry hasn't
+/// expired.
+pub fn is_initiator_denied(
+  storage: &dyn Storage,
+  time: Timestamp,
+  initiator: &Addr,
+) -> ContractResult<bool> {
+  Ok(
+    DENIED_INITIATORS
+      .may_load(storage, initiator.clone())?
+      .map(|entry| entry.is_active(time))
+      .unwrap_or(false),
+  )
+}
+
+/// Abort if the initiator is on the denylist or, when the client is in
+/// allowlist-only mode, if the initiator isn't on the client's allowlist.
+/// Expired entries are ignored.
+pub fn ensure_initiator_allowed(
+  storage: &dyn Storage,
+  time: Timestamp,
+  client_addr: &Addr,
+  client: &Client,
+  initiator: &Addr,
+) -> ContractResult<()> {
+  if is_initiator_denied(storage, time, initiator)? {
+    return Err(ContractError::InitiatorDenied);
+  }
+  if client.config.allowlist_only.unwrap_or(false) && initiator != client_addr {
+    let is_allowed = ALLOWED_INITIATORS
+      .may_load(storage, (client_addr.clone(), initiator.clone()))?
+      .map(|entry| entry.is_active(time))
+      .unwrap_or(false);
+    if !is_allowed {
+      return Err(ContractError::InitiatorNotAllowed);
+    }
+  }
+  Ok(())
+}
+
+/// Credit the initiator's referrer, if any, with the configured share of the
+/// given revenue, returning the amount credited.
+pub fn credit_referrer(
+  storage: &mut dyn Storage,
+  config: &Config,
+  initiator: &Addr,
+  revenue: Uint128,
+) -> ContractResult<Uint128> {
+  let rate = config.referral_rate.unwrap_or_default();
+  if rate.is_zero() {
+    return Ok(Uint128::zero());
+  }
+  let referrer = match REFERRERS.may_load(storage, initiator.clone())? {
+    Some(referrer) => referrer,
+    None => return Ok(Uint128::zero()),
+  };
+  let amount = mul_pct(revenue, rate);
+  if !amount.is_zero() {
+    REFERRAL_ACCOUNTS.update(storage, referrer, |maybe_account| -> ContractResult<_> {
+      let mut account = maybe_account.unwrap_or_else(|| ReferralAccount::new());
+      account.earned += amount;
+      account.balance += amount;
+      Ok(account)
+    })?;
+  }
+  Ok(amount)
+}
+
+/// Move each applicable jackpot's share of the given revenue into its balance,
+/// without letting the total exceed the available amount. Returns the total
+/// contributed.
+pub fn contribute_to_jackpots(
+  storage: &mut dyn Storage,
+  client_addr: &Addr,
+  revenue: Uint128,
+  available: Uint128,
+) -> ContractResult<Uint128> {
+  let jackpots: Vec<Jackpot> = JACKPOTS
+    .range(storage, None, None, Order::Ascending)
+    .map(|r| r.map(|(_, jackpot)| jackpot))
+    .collect::<StdResult<Vec<Jackpot>>>()?
+    .into_iter()
+    .filter(|j| j.client.is_none() || j.client.as_ref() == Some(client_addr))
+    .collect();
+
+  let mut total = Uint128::zero();
+
+  for mut jackpot in jackpots.into_iter() {
+    let amount = mul_pct(revenue, jackpot.contribution_pct).min(available - total);
+    if amount.is_zero() {
+      continue;
+    }
+    total += amount;
+    jackpot.balance += amount;
+    jackpot.contributed += amount;
+    JACKPOTS.save(storage, jackpot.name.clone(), &jackpot)?;
+    JACKPOT_CONTRIBUTIONS.update(
+      storage,
+      (jackpot.name.clone(), client_addr.clone()),
+      |maybe_amount| -> ContractResult<_> { Ok(maybe_amount.unwrap_or_default() + amount) },
+    )?;
+  }
+
+  Ok(total)
+}
+
+/// Load a player's limits, first applying any pending limits whose delay has
+/// elapsed.
+pub fn load_player_limits(
+  storage: &mut dyn Storage,
+  time: Timestamp,
+  player: &Addr,
+) -> ContractResult<Option<PlayerLimits>> {
+  if let Some(pending) = PENDING_PLAYER_LIMITS.may_load(storage, player.clone())? {
+    if time >= pending.eta {
+      let limits = match PLAYER_LIMITS.may_load(storage, player.clone())? {
+        Some(current) => current.loosen(&pending.limits, time),
+        None => pending.limits,
+      };
+      PENDING_PLAYER_LIMITS.remove(storage, player.clone());
+      PLAYER_LIMITS.save(storage, player.clone(), &limits)?;
+      return Ok(Some(limits));
+    }
+  }
+  Ok(PLAYER_LIMITS.may_load(storage, player.clone())?)
+}
+
+/// Abort with PlayerLimitReached if the initiator is self-excluded or if the
+/// job would take them past their wager or loss limit for the current period.
+pub fn enforce_player_limits(
+  storage: &mut dyn Storage,
+  block: &BlockInfo,
+  initiator: &Addr,
+  incoming: Uint128,
+  outgoing: Uint128,
+) -> ContractResult<()> {
+  let limits = match load_player_limits(storage, block.time, initiator)? {
+    Some(limits) => limits,
+    None => return Ok(()),
+  };
+
+  if let Some(excluded_until) = limits.excluded_until {
+    if block.time < excluded_until {
+      return Err(ContractError::PlayerLimitReached);
+    }
+  }
+  if let Some(limit) = &limits.wager_limit {
+    increment_period_usage(
+      storage,
+      &PLAYER_WAGER_USAGE,
+      block,
+      initiator,
+      limit,
+      incoming,
+    )?;
+  }
+  if let Some(limit) = &limits.loss_limit {
+    let loss = incoming.saturating_sub(outgoing);
+    increment_period_usage(storage, &PLAYER_LOSS_USAGE, block, initiator, limit, loss)?;
+  }
+  Ok(())
+}
+
+fn increment_period_usage(
+  storage: &mut dyn Storage,
+  map: &Map<Addr, PeriodUsage>,
+  block: &BlockInfo,
+  player: &Addr,
+  limit: &PeriodLimit,
+  amount: Uint128,
+) -> ContractResult<()> {
+  if amount.is_zero() {
+    return Ok(());
+  }
+  let time = block.time.seconds();
+  let start = Timestamp::from_seconds(time - (time % limit.period.seconds()));
+  let mut usage = map
+    .may_load(storage, player.clone())?
+    .filter(|usage| usage.start == start)
+    .unwrap_or_else(|| PeriodUsage {
+      start,
+      amount: Uint128::zero(),
+    });
+  usage.amount += amount;
+  if usage.amount > limit.amount {
+    return Err(ContractError::PlayerLimitReached);
+  }
+  map.save(storage, player.clone(), &usage)?;
+  Ok(())
+}
+
 pub fn suspend_client(
   storage: &mut dyn Storage,
   client_addr: &Addr,
@@ -92,8 +692,7 @@ pub fn suspend_client(
         client.is_suspended = true;
         Ok(client)
       } else {
-        // client not found
-        Err(ContractError::NotAuthorized {})
+        Err(ContractError::ClientNotFound)
       }
     },
   )?;
@@ -137,18 +736,65 @@ pub fn load_client(
 }
 
 /// Compute an account's owed dividends, liquidity, losses, updating contract
-/// state. The computed values are stored in the StakeAccount.
+/// state. The computed values are stored in the StakeAccount. If the account's
+/// lockup has expired, its liquidity goes back to being weighted at 1x in its
+/// tranche, which saves the pool.
 pub fn sync_account(
   storage: &mut dyn Storage,
   api: &dyn Api,
+  time: Timestamp,
   account: &mut StakeAccount,
   is_claiming: bool,
 ) -> ContractResult<()> {
+  let lapsed_lockup = account
+    .lockup
+    .clone()
+    .filter(|lockup| time >= lockup.expires_at);
+
   // Empty ledger implies every account is fulled synced.
-  if N_LEDGER_ENTRIES.load(storage)? == 0 {
-    return Ok(());
+  if N_LEDGER_ENTRIES.load(storage)? > 0 {
+    // A lapsing lockup closes the current ledger entry below, so it's safe to
+    // sync it in full.
+    sync_ledger_entries(
+      storage,
+      api,
+      account,
+      is_claiming || lapsed_lockup.is_some(),
+    )?;
   }
 
+  if let Some(lockup) = lapsed_lockup {
+    let mut pool = POOL.load(storage)?;
+    let tranche = account.tranche();
+    let mut totals = pool.tranche(&tranche);
+    let tranche_liquidity = totals.liquidity;
+    reweight_tranche(
+      &mut totals,
+      tranche_liquidity,
+      account.liquidity,
+      lockup.multiplier,
+      account.liquidity,
+      Uint128::from(PCT_DENOMINATOR),
+    )?;
+    pool.set_tranche(&tranche, totals);
+    POOL.save(storage, &pool)?;
+    account.lockup = None;
+
+    // the tranche's multiplier changed, so start a new ledger entry
+    increment(storage, &N_DELEGATION_MUTATIONS, Uint128::one())?;
+  }
+
+  Ok(())
+}
+
+/// Apply unsynced ledger entries to an account and save the entries' updated
+/// reference counts.
+fn sync_ledger_entries(
+  storage: &mut dyn Storage,
+  api: &dyn Api,
+  account: &mut StakeAccount,
+  is_claiming: bool,
+) -> ContractResult<()> {
   // update the account's computed liquidity and dividends, returning any info
   // necessary to save changes to state below.
   let updates = sync_account_readonly(storage, api, account, is_claiming)?;
@@ -196,17 +842,52 @@ pub fn sync_account_readonly(
     for i_entry in account.seq_no.u128()..current_seq_no.u128() {
       let mut entry = LEDGER.load(storage, i_entry)?;
 
-      let gain = entry
-        .delta_revenue
-        .multiply_ratio(account.liquidity, entry.liquidity);
+      // Use the ledger accounting of the account's own tranche, or of its
+      // tranche's unbonding bucket if its unbonding liquidity is still at
+      // risk. Entries
+      // that predate tranches only have senior accounting.
+      let tranche_entry = if account.is_unbonding_at_risk() {
+        entry.unbonding_entry(&account.tranche())
+      } else {
+        match account.tranche() {
+          Tranche::Senior => entry.senior(),
+          Tranche::Junior => entry
+            .junior
+            .clone()
+            .unwrap_or_else(|| TrancheLedgerEntry::new(Uint128::zero(), None)),
+        }
+      };
 
-      let loss = entry
-        .delta_loss
-        .multiply_ratio(account.liquidity, entry.liquidity);
+      let mut gain = Uint128::zero();
+      let mut loss = Uint128::zero();
+      let mut dividends = Uint128::zero();
 
-      let dividends = entry
-        .delta_dividends
-        .multiply_ratio(account.liquidity, entry.liquidity);
+      if !tranche_entry.liquidity.is_zero() {
+        gain = tranche_entry
+          .delta_revenue
+          .multiply_ratio(account.liquidity, tranche_entry.liquidity);
+
+        // Round losses up so that the accounts in a tranche never hold more
+        // liquidity than the tranche itself.
+        loss = Uint128::try_from(
+          (tranche_entry.delta_loss.full_mul(account.liquidity)
+            + Uint256::from(tranche_entry.liquidity - Uint128::one()))
+            / Uint256::from(tranche_entry.liquidity),
+        )
+        .map_err(|e| ContractError::Std(StdError::generic_err(e.to_string())))?;
+
+        // Dividends are shared by liquidity weighted by lockup multiplier,
+        // where the entry's multiplier is the tranche's weighted average.
+        dividends = tranche_entry
+          .delta_dividends
+          .multiply_ratio(account.liquidity, tranche_entry.liquidity)
+          .multiply_ratio(
+            account.multiplier(),
+            tranche_entry
+              .multiplier
+              .unwrap_or(Uint128::from(PCT_DENOMINATOR)),
+          );
+      }
 
       entry.ref_count -= 1;
 
@@ -225,6 +906,18 @@ pub fn sync_account_readonly(
   Ok(updates)
 }
 
+/// Add a staker's address to the memoization queue unless it's already queued.
+pub fn enqueue_for_memoization(
+  storage: &mut dyn Storage,
+  addr: &Addr,
+) -> ContractResult<()> {
+  if !MEMOIZATION_QUEUE_MEMBERS.has(storage, addr.clone()) {
+    MEMOIZATION_QUEUE.push_back(storage, addr)?;
+    MEMOIZATION_QUEUE_MEMBERS.save(storage, addr.clone(), &true)?;
+  }
+  Ok(())
+}
+
 /// Rotate through existing staking contracts, eagerly syncing them. This
 /// amortizes the runtime of the claim and unstake functions, which would
 /// otherwise need to iterate through every single ledger entry created
@@ -232,25 +925,47 @@ pub fn sync_account_readonly(
 pub fn amortize(
   storage: &mut dyn Storage,
   api: &dyn Api,
+  time: Timestamp,
 ) -> Result<(), ContractError> {
   let curr_seq_no = LEDGER_ENTRY_SEQ_NO.load(storage)?;
   for _ in 0..2 {
     if let Some(addr) = MEMOIZATION_QUEUE.pop_front(storage)? {
-      if let Some(mut account) = STAKE_ACCOUNTS.may_load(storage, addr.clone())? {
-        if account.unbonding.is_none() {
-          MEMOIZATION_QUEUE.push_back(storage, &addr)?;
-          if account.seq_no < curr_seq_no.into() {
-            sync_account(storage, api, &mut account, false)?;
-            STAKE_ACCOUNTS.save(storage, addr.clone(), &account)?;
-            break;
-          }
+      let maybe_account = STAKE_ACCOUNTS
+        .may_load(storage, addr.clone())?
+        .filter(|account| account.unbonding.is_none() || account.is_unbonding_at_risk());
+      if let Some(mut account) = maybe_account {
+        MEMOIZATION_QUEUE.push_back(storage, &addr)?;
+        let has_lapsed_lockup = account
+          .lockup
+          .as_ref()
+          .map(|lockup| time >= lockup.expires_at)
+          .unwrap_or(false);
+        if account.seq_no < curr_seq_no.into() || has_lapsed_lockup {
+          sync_account(storage, api, time, &mut account, false)?;
+          STAKE_ACCOUNTS.save(storage, addr.clone(), &account)?;
+          break;
         }
+      } else {
+        // the address no longer needs syncing, so let it be queued again later
+        MEMOIZATION_QUEUE_MEMBERS.remove(storage, addr);
       }
     }
   }
   Ok(())
 }
 
+/// Return error if the given scope has been paused.
+pub fn ensure_not_paused(
+  storage: &dyn Storage,
+  scope: PauseScope,
+) -> ContractResult<()> {
+  if PAUSED_SCOPES.load(storage)?.contains(&scope) {
+    Err(ContractError::Paused)
+  } else {
+    Ok(())
+  }
+}
+
 pub fn ensure_client_not_rate_limited(client: &Client) -> ContractResult<()> {
   if client.is_suspended {
     Err(ContractError::ClientSuspended)
@@ -342,9 +1057,190 @@ pub fn ensure_sender_is_allowed(
   }
 }
 
+/// Return error if adding the given amount to a staker's delegation would
+/// violate the configured stake limits, pool capacity or staker allowlist.
+pub fn ensure_can_stake(
+  deps: &Deps,
+  config: &Config,
+  pool: &Pool,
+  staker: &Addr,
+  prev_delegation: Uint128,
+  amount: Uint128,
+) -> ContractResult<()> {
+  let delegation = prev_delegation + amount;
+  if let Some(min_stake) = config.min_stake {
+    if delegation < min_stake {
+      return Err(ContractError::StakeBelowMinimum);
+    }
+  }
+  if let Some(max_stake) = config.max_stake {
+    if delegation > max_stake {
+      return Err(ContractError::StakeAboveMaximum);
+    }
+  }
+  if let Some(max_pool_delegation) = config.max_pool_delegation {
+    if pool.delegation + amount > max_pool_delegation {
+      return Err(ContractError::PoolCapacityExceeded);
+    }
+  }
+  if let Some(acl_addr) = &config.staker_acl {
+    let acl = Acl::new(acl_addr);
+    if !acl.is_allowed(&deps.querier, staker, "/house/stake")? {
+      return Err(ContractError::StakerNotAllowed);
+    }
+  }
+  Ok(())
+}
+
+/// Update a tranche's liquidity-weighted average multiplier after an
+/// account's liquidity or multiplier changes. The tranche's liquidity must
+/// already reflect the change. Revenue and losses scale every account's
+/// liquidity in a tranche by the same factor, so the average only changes here.
+pub fn reweight_tranche(
+  totals: &mut TrancheTotals,
+  prev_tranche_liquidity: Uint128,
+  prev_account_liquidity: Uint128,
+  prev_account_multiplier: Uint128,
+  account_liquidity: Uint128,
+  account_multiplier: Uint128,
+) -> ContractResult<()> {
+  let one = Uint128::from(PCT_DENOMINATOR);
+  if totals.liquidity.is_zero() {
+    totals.multiplier = None;
+    return Ok(());
+  }
+  let prev_weight = prev_tranche_liquidity.full_mul(totals.multiplier.unwrap_or(one));
+  let weight = prev_weight.saturating_sub(prev_account_liquidity.full_mul(prev_account_multiplier))
+    + account_liquidity.full_mul(account_multiplier);
+  let multiplier = Uint128::try_from(weight / Uint256::from(totals.liquidity))
+    .map_err(|e| ContractError::Std(StdError::generic_err(e.to_string())))?;
+  totals.multiplier = if multiplier > one {
+    Some(multiplier)
+  } else {
+    None
+  };
+  Ok(())
+}
+
+/// Revenue, dividends and losses allocated to a single tranche.
+#[derive(Default)]
+struct TrancheDeltas {
+  revenue: Uint128,
+  dividends: Uint128,
+  loss: Uint128,
+}
+
+/// Ledger deltas split between the tranches and their unbonding buckets.
+#[derive(Default)]
+struct LedgerSplit {
+  senior: TrancheDeltas,
+  junior: TrancheDeltas,
+  senior_unbonding: TrancheDeltas,
+  junior_unbonding: TrancheDeltas,
+}
+
+/// Split ledger deltas between the senior and junior tranches and the at-risk
+/// liquidity unbonding from each. The junior tranche earns revenue and
+/// dividends with its liquidity weighted by the configured multiplier. Junior
+/// liquidity, including liquidity still unbonding from it, absorbs losses
+/// first, up to its total. The senior tranche and its unbonding bucket share
+/// the remaining losses pro rata. Unbonding liquidity only shares in revenue
+/// and dividends if configured to.
+fn split_by_tranche(
+  storage: &dyn Storage,
+  pool: &Pool,
+  delta_revenue: Uint128,
+  delta_dividends: Uint128,
+  delta_loss: Uint128,
+) -> ContractResult<LedgerSplit> {
+  let config = CONFIG.load(storage)?;
+  let junior_multiplier = config
+    .junior_revenue_multiplier
+    .unwrap_or(Uint128::from(DEFAULT_JUNIOR_REVENUE_MULTIPLIER));
+
+  let senior = pool.tranche(&Tranche::Senior);
+  let junior = pool.tranche(&Tranche::Junior);
+  let senior_unbonding = pool.unbonding_totals(&Tranche::Senior);
+  let junior_unbonding = pool.unbonding_totals(&Tranche::Junior);
+
+  let junior_weight = mul_pct(junior.liquidity, junior_multiplier);
+  let (senior_unbonding_weight, junior_unbonding_weight) =
+    if config.unbonding_shares_gains.unwrap_or(false) {
+      (
+        senior_unbonding.liquidity,
+        mul_pct(junior_unbonding.liquidity, junior_multiplier),
+      )
+    } else {
+      (Uint128::zero(), Uint128::zero())
+    };
+  let total_weight =
+    senior.liquidity + junior_weight + senior_unbonding_weight + junior_unbonding_weight;
+
+  let mut split = LedgerSplit::default();
+
+  if !total_weight.is_zero() {
+    for (deltas, weight) in [
+      (&mut split.junior, junior_weight),
+      (&mut split.senior_unbonding, senior_unbonding_weight),
+      (&mut split.junior_unbonding, junior_unbonding_weight),
+    ] {
+      deltas.revenue = delta_revenue.multiply_ratio(weight, total_weight);
+      deltas.dividends = delta_dividends.multiply_ratio(weight, total_weight);
+    }
+  }
+
+  let junior_total = junior.liquidity + junior_unbonding.liquidity;
+  let junior_loss = delta_loss.min(junior_total);
+  if !junior_total.is_zero() {
+    split.junior_unbonding.loss =
+      junior_loss.multiply_ratio(junior_unbonding.liquidity, junior_total);
+  }
+  split.junior.loss = junior_loss - split.junior_unbonding.loss;
+
+  let remaining_loss = delta_loss - junior_loss;
+  let senior_total = senior.liquidity + senior_unbonding.liquidity;
+  if !senior_total.is_zero() {
+    split.senior_unbonding.loss = remaining_loss
+      .multiply_ratio(senior_unbonding.liquidity, senior_total)
+      .min(senior_unbonding.liquidity);
+  }
+
+  split.senior = TrancheDeltas {
+    revenue: delta_revenue
+      - split.junior.revenue
+      - split.senior_unbonding.revenue
+      - split.junior_unbonding.revenue,
+    dividends: delta_dividends
+      - split.junior.dividends
+      - split.senior_unbonding.dividends
+      - split.junior_unbonding.dividends,
+    loss: remaining_loss - split.senior_unbonding.loss,
+  };
+
+  Ok(split)
+}
+
+/// Add a tranche's deltas to its accounting in an existing ledger entry,
+/// creating the accounting from the tranche's totals if it doesn't exist yet.
+fn accumulate_tranche_entry(
+  maybe_entry: Option<TrancheLedgerEntry>,
+  totals: &TrancheTotals,
+  deltas: &TrancheDeltas,
+) -> TrancheLedgerEntry {
+  let mut entry =
+    maybe_entry.unwrap_or_else(|| TrancheLedgerEntry::new(totals.liquidity, totals.multiplier));
+  entry.delta_revenue += deltas.revenue;
+  entry.delta_dividends += deltas.dividends;
+  entry.delta_loss += deltas.loss;
+  entry
+}
+
+/// Record revenue, dividends and losses in the ledger, split by tranche, and
+/// update the pool's per-tranche liquidity. The caller is responsible for
+/// updating the pool's total liquidity and dividends.
 pub fn upsert_ledger_entry(
   storage: &mut dyn Storage,
-  pool: &Pool,
+  pool: &mut Pool,
   delta_revenue: Uint128,
   delta_dividends: Uint128,
   delta_loss: Uint128,
@@ -353,41 +1249,613 @@ pub fn upsert_ledger_entry(
   let seq_no = LEDGER_ENTRY_SEQ_NO.load(storage)?;
   let tag = N_DELEGATION_MUTATIONS.load(storage)?;
 
+  let split = split_by_tranche(storage, pool, delta_revenue, delta_dividends, delta_loss)?;
+
+  let mut senior = pool.tranche(&Tranche::Senior);
+  let mut junior = pool.tranche(&Tranche::Junior);
+  let mut senior_unbonding = pool.unbonding_totals(&Tranche::Senior);
+  let mut junior_unbonding = pool.unbonding_totals(&Tranche::Junior);
+
   // First, we try to increment the latest existing entry instead of making a
   // new one. we do this to keep the number of new entries created at a minimum
   // to help amortize the sync process.
+  let mut maybe_entry: Option<LedgerEntry> = None;
   if n_entries > 0 {
     let i_curr_entry = seq_no.u128() - 1u128;
     let mut curr_entry = LEDGER.load(storage, i_curr_entry)?;
     if curr_entry.tag == tag {
-      curr_entry.delta_revenue += delta_revenue;
-      curr_entry.delta_dividends += delta_dividends;
-      curr_entry.delta_loss += delta_loss;
+      curr_entry.delta_revenue += split.senior.revenue;
+      curr_entry.delta_dividends += split.senior.dividends;
+      curr_entry.delta_loss += split.senior.loss;
+      curr_entry.junior = Some(accumulate_tranche_entry(
+        curr_entry.junior.clone(),
+        &junior,
+        &split.junior,
+      ));
+      curr_entry.unbonding = Some(accumulate_tranche_entry(
+        curr_entry.unbonding.clone(),
+        &senior_unbonding,
+        &split.senior_unbonding,
+      ));
+      curr_entry.junior_unbonding = Some(accumulate_tranche_entry(
+        curr_entry.junior_unbonding.clone(),
+        &junior_unbonding,
+        &split.junior_unbonding,
+      ));
       LEDGER.save(storage, i_curr_entry, &curr_entry)?;
-      return Ok(curr_entry);
+      maybe_entry = Some(curr_entry);
     }
   }
 
-  // `ref_count` is the number of accounts that will need to refer to the
-  // pending LedgerEntry when syncing it. Unbonding accounts are ignored.
-  let ref_count = N_STAKE_ACCOUNTS.load(storage)? - N_STAKE_ACCOUNTS_UNBONDING.load(storage)?;
-
-  // if we weren't able to increment the latest existing entry,
-  // we create a new one here.
-  let entry = LedgerEntry {
-    liquidity: pool.liquidity,
-    delegation: pool.delegation,
-    delta_loss,
-    delta_revenue,
-    delta_dividends,
-    ref_count,
-    tag,
+  let entry = if let Some(entry) = maybe_entry {
+    entry
+  } else {
+    // `ref_count` is the number of accounts that will need to refer to the
+    // pending LedgerEntry when syncing it. Unbonding accounts are ignored,
+    // unless their unbonding liquidity still carries risk.
+    let ref_count = N_STAKE_ACCOUNTS.load(storage)? - N_STAKE_ACCOUNTS_UNBONDING.load(storage)?
+      + N_STAKE_ACCOUNTS_UNBONDING_AT_RISK.load(storage)?;
+
+    // if we weren't able to increment the latest existing entry, we create a
+    // new one here. Its top-level fields belong to the senior tranche.
+    let entry = LedgerEntry {
+      liquidity: senior.liquidity,
+      delegation: senior.delegation,
+      delta_loss: split.senior.loss,
+      delta_revenue: split.senior.revenue,
+      delta_dividends: split.senior.dividends,
+      ref_count,
+      tag,
+      multiplier: senior.multiplier,
+      junior: Some(accumulate_tranche_entry(None, &junior, &split.junior)),
+      unbonding: Some(accumulate_tranche_entry(
+        None,
+        &senior_unbonding,
+        &split.senior_unbonding,
+      )),
+      junior_unbonding: Some(accumulate_tranche_entry(
+        None,
+        &junior_unbonding,
+        &split.junior_unbonding,
+      )),
+    };
+
+    LEDGER.save(storage, seq_no.into(), &entry)?;
+
+    increment(storage, &N_LEDGER_ENTRIES, 1)?;
+    increment(storage, &LEDGER_ENTRY_SEQ_NO, Uint128::one())?;
+
+    entry
   };
 
-  LEDGER.save(storage, seq_no.into(), &entry)?;
+  senior.liquidity = senior.liquidity + split.senior.revenue - split.senior.loss;
+  junior.liquidity = junior.liquidity + split.junior.revenue - split.junior.loss;
+  senior_unbonding.liquidity =
+    senior_unbonding.liquidity + split.senior_unbonding.revenue - split.senior_unbonding.loss;
+  junior_unbonding.liquidity =
+    junior_unbonding.liquidity + split.junior_unbonding.revenue - split.junior_unbonding.loss;
 
-  increment(storage, &N_LEDGER_ENTRIES, 1)?;
-  increment(storage, &LEDGER_ENTRY_SEQ_NO, Uint128::one())?;
+  pool.set_tranche(&Tranche::Senior, senior);
+  pool.set_tranche(&Tranche::Junior, junior);
+  pool.set_unbonding_totals(&Tranche::Senior, senior_unbonding);
+  pool.set_unbonding_totals(&Tranche::Junior, junior_unbonding);
 
   Ok(entry)
 }
+
+/// Return the storage key of a reward token.
+pub fn reward_token_key(token: &Token) -> String {
+  match token {
+    Token::Native { denom } => denom.clone(),
+    Token::Cw20 { address } => address.to_string(),
+  }
+}
+
+/// Return every reward token with its index advanced to the given time, along
+/// with the advanced state of each reward stream. Streams that have ended are
+/// returned as None, since their emissions are fully reflected in their
+/// token's index. Emissions made while nothing is staked are not distributed.
+fn advance_rewards(
+  storage: &dyn Storage,
+  time: Timestamp,
+  total_delegation: Uint128,
+) -> ContractResult<(Vec<(String, RewardToken)>, Vec<(u64, Option<RewardStream>)>)> {
+  let mut reward_tokens = REWARD_TOKENS
+    .range(storage, None, None, Order::Ascending)
+    .collect::<Result<Vec<(String, RewardToken)>, StdError>>()?;
+
+  let mut streams: Vec<(u64, Option<RewardStream>)> = vec![];
+
+  for result in REWARD_STREAMS.range(storage, None, None, Order::Ascending) {
+    let (id, mut stream) = result?;
+    let key = reward_token_key(&stream.token);
+    let (_, reward_token) = reward_tokens
+      .iter_mut()
+      .find(|(k, _)| *k == key)
+      .ok_or(ContractError::RewardTokenNotFound)?;
+
+    let from = reward_token.updated_at.max(stream.start);
+    let to = time.min(stream.end);
+
+    if to > from {
+      let remaining = stream.amount - stream.emitted;
+      let emission = if to == stream.end {
+        remaining
+      } else {
+        stream
+          .amount
+          .multiply_ratio(
+            to.seconds() - from.seconds(),
+            stream.end.seconds() - stream.start.seconds(),
+          )
+          .min(remaining)
+      };
+      stream.emitted += emission;
+      if !total_delegation.is_zero() {
+        reward_token.index += emission.multiply_ratio(REWARD_INDEX_SCALE, total_delegation);
+        reward_token.distributed += emission;
+      }
+    }
+
+    streams.push((
+      id,
+      if time >= stream.end {
+        None
+      } else {
+        Some(stream)
+      },
+    ));
+  }
+
+  for (_, reward_token) in reward_tokens.iter_mut() {
+    reward_token.updated_at = reward_token.updated_at.max(time);
+  }
+
+  Ok((reward_tokens, streams))
+}
+
+/// Advance every reward token's index to the current time and accrue the
+/// given account's rewards, based on its delegation before any change. This
+/// must be called before either the account's or the pool's delegation
+/// changes.
+pub fn sync_rewards(
+  storage: &mut dyn Storage,
+  time: Timestamp,
+  addr: &Addr,
+  delegation: Uint128,
+) -> ContractResult<()> {
+  let pool = POOL.load(storage)?;
+  let (reward_tokens, streams) = advance_rewards(storage, time, pool.delegation)?;
+
+  for (id, maybe_stream) in streams.iter() {
+    if let Some(stream) = maybe_stream {
+      REWARD_STREAMS.save(storage, *id, stream)?;
+    } else {
+      REWARD_STREAMS.remove(storage, *id);
+    }
+  }
+
+  for (key, reward_token) in reward_tokens.iter() {
+    REWARD_TOKENS.save(storage, key.clone(), reward_token)?;
+    let map_key = (key.clone(), addr.clone());
+    // Checkpoint the account even when it has no delegation yet, so that a new
+    // staker starts accruing from the current index rather than from zero.
+    // Since every delegation change syncs first, an account is only missing
+    // here with delegation if it was staked before the token's first stream,
+    // when the token's index was zero.
+    let mut account = REWARD_ACCOUNTS
+      .may_load(storage, map_key.clone())?
+      .unwrap_or_else(|| RewardAccount::new());
+    account.accrue(reward_token, delegation);
+    REWARD_ACCOUNTS.save(storage, map_key, &account)?;
+  }
+
+  Ok(())
+}
+
+/// Return an account's rewards in each reward token as of the given time,
+/// without persisting anything.
+pub fn reward_accounts_readonly(
+  storage: &dyn Storage,
+  time: Timestamp,
+  addr: &Addr,
+  delegation: Uint128,
+) -> ContractResult<Vec<RewardAccount>> {
+  let pool = POOL.load(storage)?;
+  let (reward_tokens, _) = advance_rewards(storage, time, pool.delegation)?;
+  let mut accounts: Vec<RewardAccount> = Vec::with_capacity(reward_tokens.len());
+  for (key, reward_token) in reward_tokens.iter() {
+    let mut account = REWARD_ACCOUNTS
+      .may_load(storage, (key.clone(), addr.clone()))?
+      .unwrap_or_else(|| RewardAccount::new());
+    account.accrue(reward_token, delegation);
+    account.token = Some(reward_token.token.clone());
+    accounts.push(account);
+  }
+  Ok(accounts)
+}
+
+/// Record a staker's delegation and liquidity at the given height. Call this
+/// whenever the account's stake changes or is synced. Liquidity in snapshots
+/// is as of the account's last sync.
+pub fn snapshot_stake(
+  storage: &mut dyn Storage,
+  height: u64,
+  staker: &Addr,
+  account: &StakeAccount,
+) -> ContractResult<()> {
+  STAKE_SNAPSHOTS.save(
+    storage,
+    staker.clone(),
+    &StakeSnapshot {
+      delegation: account.delegation,
+      liquidity: account.liquidity,
+    },
+    height,
+  )?;
+  Ok(())
+}
+
+/// Record the pool's total delegation and liquidity at the given height. Call
+/// this whenever either changes.
+pub fn snapshot_pool(
+  storage: &mut dyn Storage,
+  height: u64,
+  pool: &Pool,
+) -> ContractResult<()> {
+  POOL_SNAPSHOTS.save(
+    storage,
+    &StakeSnapshot {
+      delegation: pool.delegation,
+      liquidity: pool.liquidity,
+    },
+    height,
+  )?;
+  Ok(())
+}
+
+/// Return a staker's stake and the pool's totals as of the start of the block
+/// at the given height.
+pub fn stake_at(
+  storage: &dyn Storage,
+  staker: &Addr,
+  height: u64,
+) -> ContractResult<(StakeSnapshot, StakeSnapshot)> {
+  let empty = StakeSnapshot {
+    delegation: Uint128::zero(),
+    liquidity: Uint128::zero(),
+  };
+  Ok((
+    STAKE_SNAPSHOTS
+      .may_load_at_height(storage, staker.clone(), height)?
+      .unwrap_or_else(|| empty.clone()),
+    POOL_SNAPSHOTS
+      .may_load_at_height(storage, height)?
+      .unwrap_or(empty),
+  ))
+}
+
+/// Return a staker's voting power and the total voting power, which are their
+/// delegations as of the start of the block at the given height.
+pub fn voting_power_at(
+  storage: &dyn Storage,
+  staker: &Addr,
+  height: u64,
+) -> ContractResult<(Uint128, Uint128)> {
+  let (stake, total) = stake_at(storage, staker, height)?;
+  Ok((stake.delegation, total.delegation))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use cosmwasm_std::testing::{MockApi, MockStorage};
+
+  fn config() -> Config {
+    let rate_limit = RateLimitConfig {
+      interval_seconds: Uint64::from(60u64),
+      max_pct_change: Uint128::from(PCT_DENOMINATOR),
+    };
+    Config {
+      restake_rate: Uint128::zero(),
+      tax_rate: Uint128::zero(),
+      unbonding_seconds: Uint64::zero(),
+      account_rate_limit: rate_limit.clone(),
+      default_client_rate_limit: rate_limit,
+      timelock_seconds: None,
+      max_event_log_size: None,
+      large_payout_threshold: None,
+      stats_bucket_seconds: None,
+      stats_retention_buckets: None,
+      player_stats_enabled: None,
+      player_limits_delay_seconds: None,
+      rakeback_rate: None,
+      referral_rate: None,
+      lockup_tiers: None,
+      junior_revenue_multiplier: None,
+      unbonding_shares_losses: None,
+      unbonding_shares_gains: None,
+      max_pool_delegation: None,
+      min_stake: None,
+      max_stake: None,
+      staker_acl: None,
+      governance: None,
+    }
+  }
+
+  fn token() -> Token {
+    Token::Native {
+      denom: "ujuno".to_owned(),
+    }
+  }
+
+  fn totals(liquidity: u128) -> TrancheTotals {
+    TrancheTotals {
+      delegation: Uint128::from(liquidity),
+      liquidity: Uint128::from(liquidity),
+      multiplier: None,
+    }
+  }
+
+  /// Save the state that the ledger functions expect, with the given number of
+  /// stakers and a pool holding the given senior and junior liquidity.
+  fn setup(
+    storage: &mut dyn Storage,
+    config: &Config,
+    n_stakers: u32,
+    senior: u128,
+    junior: u128,
+  ) -> Pool {
+    CONFIG.save(storage, config).unwrap();
+    LEDGER_ENTRY_SEQ_NO.save(storage, &Uint128::zero()).unwrap();
+    N_LEDGER_ENTRIES.save(storage, &0).unwrap();
+    N_DELEGATION_MUTATIONS
+      .save(storage, &Uint128::zero())
+      .unwrap();
+    N_STAKE_ACCOUNTS.save(storage, &n_stakers).unwrap();
+    N_STAKE_ACCOUNTS_UNBONDING.save(storage, &0).unwrap();
+    N_STAKE_ACCOUNTS_UNBONDING_AT_RISK
+      .save(storage, &0)
+      .unwrap();
+
+    let mut pool = Pool::new(&token());
+    pool.delegation = Uint128::from(senior + junior);
+    pool.liquidity = Uint128::from(senior + junior);
+    pool.set_tranche(&Tranche::Senior, totals(senior));
+    pool.set_tranche(&Tranche::Junior, totals(junior));
+    POOL.save(storage, &pool).unwrap();
+    pool
+  }
+
+  #[test]
+  fn split_by_tranche_weights_junior_revenue_and_takes_junior_losses_first() {
+    let mut storage = MockStorage::new();
+    let pool = setup(&mut storage, &config(), 2, 1000, 1000);
+
+    let split = split_by_tranche(
+      &storage,
+      &pool,
+      Uint128::from(1000u128),
+      Uint128::from(100u128),
+      Uint128::from(1500u128),
+    )
+    .unwrap();
+
+    // Junior liquidity is weighted at the default 1.5x.
+    assert_eq!(split.junior.revenue, Uint128::from(600u128));
+    assert_eq!(split.senior.revenue, Uint128::from(400u128));
+    assert_eq!(split.junior.dividends, Uint128::from(60u128));
+    assert_eq!(split.senior.dividends, Uint128::from(40u128));
+
+    // Junior liquidity absorbs losses until it's exhausted.
+    assert_eq!(split.junior.loss, Uint128::from(1000u128));
+    assert_eq!(split.senior.loss, Uint128::from(500u128));
+    assert!(split.senior_unbonding.loss.is_zero());
+    assert!(split.junior_unbonding.loss.is_zero());
+  }
+
+  #[test]
+  fn split_by_tranche_shares_with_unbonding_liquidity_at_risk() {
+    let mut storage = MockStorage::new();
+    let mut config = config();
+    config.unbonding_shares_gains = Some(true);
+    let mut pool = setup(&mut storage, &config, 2, 600, 0);
+    pool.set_unbonding_totals(&Tranche::Senior, totals(400));
+
+    let split = split_by_tranche(
+      &storage,
+      &pool,
+      Uint128::from(1000u128),
+      Uint128::zero(),
+      Uint128::from(500u128),
+    )
+    .unwrap();
+
+    assert_eq!(split.senior_unbonding.revenue, Uint128::from(400u128));
+    assert_eq!(split.senior.revenue, Uint128::from(600u128));
+    assert_eq!(split.senior_unbonding.loss, Uint128::from(200u128));
+    assert_eq!(split.senior.loss, Uint128::from(300u128));
+    assert!(split.junior.revenue.is_zero());
+    assert!(split.junior.loss.is_zero());
+  }
+
+  #[test]
+  fn sync_account_readonly_applies_revenue_and_dividends() {
+    let mut storage = MockStorage::new();
+    let api = MockApi::default();
+    let mut pool = setup(&mut storage, &config(), 2, 1000, 0);
+    let mut alice = StakeAccount::new(Uint128::from(300u128), Uint128::zero());
+    let mut bob = StakeAccount::new(Uint128::from(700u128), Uint128::zero());
+
+    upsert_ledger_entry(
+      &mut storage,
+      &mut pool,
+      Uint128::from(100u128),
+      Uint128::from(50u128),
+      Uint128::zero(),
+    )
+    .unwrap();
+
+    // The latest entry can still be accumulated, so only a final sync applies
+    // it.
+    let updates = sync_account_readonly(&storage, &api, &mut alice, false).unwrap();
+    assert!(updates.updated_entries.is_empty());
+    assert_eq!(alice.liquidity, Uint128::from(300u128));
+
+    let updates = sync_account_readonly(&storage, &api, &mut alice, true).unwrap();
+    assert_eq!(alice.liquidity, Uint128::from(330u128));
+    assert_eq!(alice.dividends, Uint128::from(15u128));
+    assert_eq!(alice.seq_no, Uint128::one());
+    assert_eq!(updates.updated_entries.len(), 1);
+    assert_eq!(updates.updated_entries[0].1.ref_count, 1);
+    assert!(updates.zombie_entry_indices.is_empty());
+    for (i_entry, entry) in updates.updated_entries.iter() {
+      LEDGER.save(&mut storage, *i_entry, entry).unwrap();
+    }
+
+    // The last account to sync an entry releases it.
+    let updates = sync_account_readonly(&storage, &api, &mut bob, true).unwrap();
+    assert_eq!(bob.liquidity, Uint128::from(770u128));
+    assert_eq!(bob.dividends, Uint128::from(35u128));
+    assert!(updates.updated_entries.is_empty());
+    assert_eq!(updates.zombie_entry_indices, vec![0u128]);
+
+    assert_eq!(
+      alice.liquidity + bob.liquidity,
+      pool.tranche(&Tranche::Senior).liquidity
+    );
+  }
+
+  #[test]
+  fn sync_account_readonly_rounds_losses_up() {
+    let mut storage = MockStorage::new();
+    let api = MockApi::default();
+    let mut pool = setup(&mut storage, &config(), 2, 1000, 0);
+    let mut alice = StakeAccount::new(Uint128::from(300u128), Uint128::zero());
+    let mut bob = StakeAccount::new(Uint128::from(700u128), Uint128::zero());
+
+    upsert_ledger_entry(
+      &mut storage,
+      &mut pool,
+      Uint128::zero(),
+      Uint128::zero(),
+      Uint128::from(101u128),
+    )
+    .unwrap();
+
+    sync_account_readonly(&storage, &api, &mut alice, true).unwrap();
+    sync_account_readonly(&storage, &api, &mut bob, true).unwrap();
+
+    assert_eq!(alice.liquidity, Uint128::from(269u128));
+    assert_eq!(bob.liquidity, Uint128::from(629u128));
+    assert!(alice.liquidity + bob.liquidity <= pool.tranche(&Tranche::Senior).liquidity);
+  }
+
+  #[test]
+  fn sync_account_readonly_uses_own_tranche() {
+    let mut storage = MockStorage::new();
+    let api = MockApi::default();
+    let mut pool = setup(&mut storage, &config(), 2, 1000, 1000);
+    let mut senior = StakeAccount::new(Uint128::from(1000u128), Uint128::zero());
+    let mut junior = StakeAccount::new(Uint128::from(1000u128), Uint128::zero());
+    junior.tranche = Some(Tranche::Junior);
+
+    upsert_ledger_entry(
+      &mut storage,
+      &mut pool,
+      Uint128::from(1000u128),
+      Uint128::zero(),
+      Uint128::from(200u128),
+    )
+    .unwrap();
+
+    sync_account_readonly(&storage, &api, &mut senior, true).unwrap();
+    sync_account_readonly(&storage, &api, &mut junior, true).unwrap();
+
+    assert_eq!(senior.liquidity, Uint128::from(1400u128));
+    assert_eq!(junior.liquidity, Uint128::from(1400u128));
+    assert_eq!(senior.liquidity, pool.tranche(&Tranche::Senior).liquidity);
+    assert_eq!(junior.liquidity, pool.tranche(&Tranche::Junior).liquidity);
+  }
+
+  #[test]
+  fn sync_rewards_accrues_stream_emissions() {
+    let mut storage = MockStorage::new();
+    setup(&mut storage, &config(), 2, 1000, 0);
+    let start = Timestamp::from_seconds(1_000);
+    let key = reward_token_key(&token());
+    let alice = Addr::unchecked("alice");
+    let bob = Addr::unchecked("bob");
+
+    REWARD_TOKENS
+      .save(
+        &mut storage,
+        key.clone(),
+        &RewardToken {
+          token: token(),
+          index: Uint128::zero(),
+          updated_at: start,
+          funded: Uint128::from(1000u128),
+          distributed: Uint128::zero(),
+          claimed: Uint128::zero(),
+        },
+      )
+      .unwrap();
+    REWARD_STREAMS
+      .save(
+        &mut storage,
+        0,
+        &RewardStream {
+          token: token(),
+          funder: Addr::unchecked("funder"),
+          amount: Uint128::from(1000u128),
+          emitted: Uint128::zero(),
+          start,
+          end: start.plus_seconds(100),
+        },
+      )
+      .unwrap();
+
+    sync_rewards(&mut storage, start, &alice, Uint128::from(250u128)).unwrap();
+
+    // Halfway through the stream, half of it has been emitted.
+    sync_rewards(
+      &mut storage,
+      start.plus_seconds(50),
+      &alice,
+      Uint128::from(250u128),
+    )
+    .unwrap();
+    let account = REWARD_ACCOUNTS
+      .load(&storage, (key.clone(), alice.clone()))
+      .unwrap();
+    assert_eq!(account.accrued, Uint128::from(125u128));
+    let stream = REWARD_STREAMS.load(&storage, 0).unwrap();
+    assert_eq!(stream.emitted, Uint128::from(500u128));
+
+    // A new staker starts accruing from the current index.
+    sync_rewards(&mut storage, start.plus_seconds(50), &bob, Uint128::zero()).unwrap();
+    let account = REWARD_ACCOUNTS.load(&storage, (key.clone(), bob)).unwrap();
+    assert!(account.accrued.is_zero());
+    assert_eq!(
+      account.index,
+      REWARD_TOKENS.load(&storage, key.clone()).unwrap().index
+    );
+
+    // Once the stream ends, its remainder is emitted and it's removed.
+    sync_rewards(
+      &mut storage,
+      start.plus_seconds(200),
+      &alice,
+      Uint128::from(250u128),
+    )
+    .unwrap();
+    let account = REWARD_ACCOUNTS
+      .load(&storage, (key.clone(), alice))
+      .unwrap();
+    assert_eq!(account.accrued, Uint128::from(250u128));
+    assert!(!REWARD_STREAMS.has(&storage, 0));
+    let reward_token = REWARD_TOKENS.load(&storage, key).unwrap();
+    assert_eq!(reward_token.distributed, Uint128::from(1000u128));
+  }
+}
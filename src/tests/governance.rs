@@ -0,0 +1,163 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage},
+  Env, OwnedDeps, Uint128, Uint64,
+};
+
+use super::{advance, config_updates, execute, instantiate, mock_config, stake};
+use crate::{
+  error::{ContractError, ContractResult},
+  models::{GovernanceConfig, ProposalAction, ProposalStatus, Vote},
+  msg::{ExecuteMsg, GovernanceMsg},
+  state::PROPOSALS,
+};
+
+type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+/// Set up a house with 50% quorum and a 60% threshold, where alice, bob and
+/// carol hold 600, 300 and 100 of voting power, and have alice propose a
+/// config change.
+fn setup() -> (MockDeps, Env) {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  let mut config = mock_config();
+  config.governance = Some(GovernanceConfig {
+    voting_period_seconds: Uint64::from(100u64),
+    quorum: Uint128::from(500_000u128),
+    threshold: Uint128::from(600_000u128),
+    min_proposer_power: None,
+  });
+  instantiate(deps.as_mut(), &env, config);
+  stake(deps.as_mut(), &env, "alice", 600).unwrap();
+  stake(deps.as_mut(), &env, "bob", 300).unwrap();
+  stake(deps.as_mut(), &env, "carol", 100).unwrap();
+
+  // voting power is snapshotted at the start of the block
+  advance(&mut env, 1);
+
+  let mut updates = config_updates();
+  updates.tax_rate = Some(Uint128::from(1000u128));
+  execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Governance(GovernanceMsg::Propose {
+      title: "Raise taxes".to_owned(),
+      description: None,
+      actions: vec![ProposalAction::SetConfig(updates)],
+    }),
+  )
+  .unwrap();
+
+  (deps, env)
+}
+
+fn vote(
+  deps: &mut MockDeps,
+  env: &Env,
+  voter: &str,
+  vote: Vote,
+) -> ContractResult<()> {
+  execute(
+    deps.as_mut(),
+    env,
+    voter,
+    &[],
+    ExecuteMsg::Governance(GovernanceMsg::Vote {
+      id: Uint64::one(),
+      vote,
+    }),
+  )?;
+  Ok(())
+}
+
+/// Cast the given votes and return the proposal's status once voting ends.
+fn status_after(votes: Vec<(&str, Vote)>) -> ProposalStatus {
+  let (mut deps, mut env) = setup();
+  for (voter, v) in votes.into_iter() {
+    vote(&mut deps, &env, voter, v).unwrap();
+  }
+  assert_eq!(
+    PROPOSALS
+      .load(&deps.storage, 1)
+      .unwrap()
+      .status(env.block.time),
+    ProposalStatus::Open
+  );
+  advance(&mut env, 100);
+  PROPOSALS
+    .load(&deps.storage, 1)
+    .unwrap()
+    .status(env.block.time)
+}
+
+#[test]
+fn proposal_needs_quorum_and_threshold_to_pass() {
+  // 100 of 1000 voted, short of the 50% quorum
+  assert_eq!(
+    status_after(vec![("carol", Vote::Yes)]),
+    ProposalStatus::Rejected
+  );
+  // quorum is met, but 300 of 900 yes is short of the 60% threshold
+  assert_eq!(
+    status_after(vec![("alice", Vote::No), ("bob", Vote::Yes)]),
+    ProposalStatus::Rejected
+  );
+  // abstentions count toward quorum but not toward the threshold
+  assert_eq!(
+    status_after(vec![("alice", Vote::Abstain), ("bob", Vote::Yes)]),
+    ProposalStatus::Passed
+  );
+  assert_eq!(
+    status_after(vec![("alice", Vote::Yes), ("bob", Vote::No)]),
+    ProposalStatus::Passed
+  );
+}
+
+#[test]
+fn only_snapshotted_stake_votes_and_only_once() {
+  let (mut deps, mut env) = setup();
+
+  // stake added after the snapshot carries no voting power
+  stake(deps.as_mut(), &env, "dave", 1000).unwrap();
+  advance(&mut env, 1);
+  let err = vote(&mut deps, &env, "dave", Vote::Yes).unwrap_err();
+  assert!(matches!(err, ContractError::InsufficientVotingPower));
+
+  vote(&mut deps, &env, "alice", Vote::Yes).unwrap();
+  let err = vote(&mut deps, &env, "alice", Vote::No).unwrap_err();
+  assert!(matches!(err, ContractError::AlreadyVoted));
+
+  let proposal = PROPOSALS.load(&deps.storage, 1).unwrap();
+  assert_eq!(proposal.yes, Uint128::from(600u128));
+  assert_eq!(proposal.total_power, Uint128::from(1000u128));
+
+  advance(&mut env, 100);
+  let err = vote(&mut deps, &env, "bob", Vote::No).unwrap_err();
+  assert!(matches!(err, ContractError::ProposalNotOpen));
+}
+
+#[test]
+fn passed_proposal_executes_once() {
+  let (mut deps, mut env) = setup();
+  vote(&mut deps, &env, "alice", Vote::Yes).unwrap();
+
+  let msg = ExecuteMsg::Governance(GovernanceMsg::Execute { id: Uint64::one() });
+
+  let err = execute(deps.as_mut(), &env, "anyone", &[], msg.clone()).unwrap_err();
+  assert!(matches!(err, ContractError::ProposalNotPassed));
+
+  advance(&mut env, 100);
+  let resp = execute(deps.as_mut(), &env, "anyone", &[], msg.clone()).unwrap();
+  assert_eq!(resp.messages.len(), 1);
+  assert_eq!(
+    PROPOSALS
+      .load(&deps.storage, 1)
+      .unwrap()
+      .status(env.block.time),
+    ProposalStatus::Executed
+  );
+
+  let err = execute(deps.as_mut(), &env, "anyone", &[], msg).unwrap_err();
+  assert!(matches!(err, ContractError::ProposalNotPassed));
+}
@@ -0,0 +1,143 @@
+use cosmwasm_std::{
+  coins,
+  testing::{mock_dependencies, mock_env},
+  Addr, Deps, Env, Timestamp, Uint128,
+};
+
+use super::{advance, connect, execute, instantiate, mock_config, query, DENOM, OWNER};
+use crate::{
+  error::ContractError,
+  models::AccountTokenAmount,
+  msg::{CanSpendResponse, ClientConfigUpdates, ClientMsg, ExecuteMsg, InitiatorMsg, QueryMsg},
+};
+
+const CLIENT: &str = "game";
+const INITIATOR: &str = "bob";
+
+fn can_spend(
+  deps: Deps,
+  env: &Env,
+  client: &str,
+) -> CanSpendResponse {
+  query(
+    deps,
+    env,
+    QueryMsg::CanSpend {
+      client: Addr::unchecked(client),
+      initiator: Addr::unchecked(INITIATOR),
+      amount: None,
+    },
+  )
+}
+
+fn deny_msg(expires_at: Option<u64>) -> ExecuteMsg {
+  ExecuteMsg::Initiators(InitiatorMsg::Deny {
+    address: Addr::unchecked(INITIATOR),
+    reason: None,
+    expires_at: expires_at.map(Timestamp::from_seconds),
+  })
+}
+
+#[test]
+fn denied_initiator_is_blocked_until_expiry() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+  connect(deps.as_mut(), &env, CLIENT);
+
+  let expires_at = env.block.time.seconds() + 100;
+  execute(deps.as_mut(), &env, OWNER, &[], deny_msg(Some(expires_at))).unwrap();
+
+  let resp = can_spend(deps.as_ref(), &env, CLIENT);
+  assert!(!resp.is_initiator_allowed);
+  assert!(!resp.can_spend);
+
+  let err = execute(
+    deps.as_mut(),
+    &env,
+    CLIENT,
+    &coins(10, DENOM),
+    ExecuteMsg::Process {
+      initiator: Addr::unchecked(INITIATOR),
+      incoming: Some(AccountTokenAmount {
+        address: Addr::unchecked(INITIATOR),
+        amount: Uint128::from(10u128),
+      }),
+      outgoing: None,
+      jackpot: None,
+    },
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::InitiatorDenied));
+
+  advance(&mut env, 100);
+  let resp = can_spend(deps.as_ref(), &env, CLIENT);
+  assert!(resp.is_initiator_allowed);
+  assert!(resp.can_spend);
+}
+
+#[test]
+fn allowlist_only_client_requires_unexpired_entry() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+  connect(deps.as_mut(), &env, CLIENT);
+
+  execute(
+    deps.as_mut(),
+    &env,
+    OWNER,
+    &[],
+    ExecuteMsg::Client(ClientMsg::SetConfig {
+      address: Addr::unchecked(CLIENT),
+      config: ClientConfigUpdates {
+        name: None,
+        description: None,
+        url: None,
+        budget: None,
+        rate_limit: None,
+        allowlist_only: Some(true),
+        rakeback_rate: None,
+        revenue_share_pct: None,
+        payout_address: None,
+      },
+    }),
+  )
+  .unwrap();
+  assert!(!can_spend(deps.as_ref(), &env, CLIENT).is_initiator_allowed);
+
+  execute(
+    deps.as_mut(),
+    &env,
+    OWNER,
+    &[],
+    ExecuteMsg::Initiators(InitiatorMsg::Allow {
+      client: Addr::unchecked(CLIENT),
+      address: Addr::unchecked(INITIATOR),
+      reason: None,
+      expires_at: Some(env.block.time.plus_seconds(100)),
+    }),
+  )
+  .unwrap();
+  assert!(can_spend(deps.as_ref(), &env, CLIENT).is_initiator_allowed);
+
+  advance(&mut env, 100);
+  assert!(!can_spend(deps.as_ref(), &env, CLIENT).is_initiator_allowed);
+}
+
+#[test]
+fn unknown_client_checks_only_the_global_denylist() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+
+  let resp = can_spend(deps.as_ref(), &env, "unknown");
+  assert!(resp.is_initiator_allowed);
+  assert!(resp.can_spend);
+
+  // only rate limits decide whether an unknown client's initiator can spend
+  execute(deps.as_mut(), &env, OWNER, &[], deny_msg(None)).unwrap();
+  let resp = can_spend(deps.as_ref(), &env, "unknown");
+  assert!(!resp.is_initiator_allowed);
+  assert!(resp.can_spend);
+}
@@ -0,0 +1,203 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env},
+  Addr, DepsMut, Env, Storage, Uint128,
+};
+
+use super::{connect, execute, instantiate, mock_config, process, stake, OWNER};
+use crate::{
+  error::ContractError,
+  msg::{ExecuteMsg, JackpotMsg},
+  state::{JACKPOTS, JACKPOT_CONTRIBUTIONS, JACKPOT_PAYOUTS},
+};
+
+const GAME_1: &str = "game1";
+const GAME_2: &str = "game2";
+const PLAYER_1: &str = "bob";
+const PLAYER_2: &str = "dave";
+
+/// Set up a house with two clients and a jackpot, fed by 10% of revenue from
+/// the given client or, if none, from both.
+fn setup(
+  mut deps: DepsMut,
+  env: &Env,
+  client: Option<&str>,
+) {
+  instantiate(deps.branch(), env, mock_config());
+  stake(deps.branch(), env, "carol", 10_000).unwrap();
+  connect(deps.branch(), env, GAME_1);
+  connect(deps.branch(), env, GAME_2);
+  execute(
+    deps,
+    env,
+    OWNER,
+    &[],
+    ExecuteMsg::Jackpots(JackpotMsg::Create {
+      name: "jackpot".to_owned(),
+      client: client.map(Addr::unchecked),
+      contribution_pct: Uint128::from(100_000u128),
+    }),
+  )
+  .unwrap();
+}
+
+fn balance(storage: &dyn Storage) -> Uint128 {
+  JACKPOTS
+    .load(storage, "jackpot".to_owned())
+    .unwrap()
+    .balance
+}
+
+#[test]
+fn global_jackpot_pays_each_client_up_to_its_contributions() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  setup(deps.as_mut(), &env, None);
+
+  process(deps.as_mut(), &env, GAME_1, PLAYER_1, 1000, 0, None).unwrap();
+  process(deps.as_mut(), &env, GAME_2, PLAYER_2, 500, 0, None).unwrap();
+  assert_eq!(balance(&deps.storage), Uint128::from(150u128));
+  assert_eq!(
+    JACKPOT_CONTRIBUTIONS
+      .load(
+        &deps.storage,
+        ("jackpot".to_owned(), Addr::unchecked(GAME_2))
+      )
+      .unwrap(),
+    Uint128::from(50u128)
+  );
+
+  // game2 can't pay out what game1 contributed
+  let err = process(
+    deps.as_mut(),
+    &env,
+    GAME_2,
+    PLAYER_2,
+    0,
+    60,
+    Some("jackpot"),
+  )
+  .unwrap_err();
+  assert!(matches!(
+    err,
+    ContractError::JackpotPayoutExceedsContributions
+  ));
+
+  process(
+    deps.as_mut(),
+    &env,
+    GAME_2,
+    PLAYER_2,
+    0,
+    50,
+    Some("jackpot"),
+  )
+  .unwrap();
+  assert_eq!(balance(&deps.storage), Uint128::from(100u128));
+  assert_eq!(
+    JACKPOT_PAYOUTS
+      .load(
+        &deps.storage,
+        ("jackpot".to_owned(), Addr::unchecked(GAME_2))
+      )
+      .unwrap(),
+    Uint128::from(50u128)
+  );
+
+  // payouts count against contributions cumulatively
+  let err = process(deps.as_mut(), &env, GAME_2, PLAYER_2, 0, 1, Some("jackpot")).unwrap_err();
+  assert!(matches!(
+    err,
+    ContractError::JackpotPayoutExceedsContributions
+  ));
+
+  process(
+    deps.as_mut(),
+    &env,
+    GAME_1,
+    PLAYER_1,
+    0,
+    100,
+    Some("jackpot"),
+  )
+  .unwrap();
+  assert!(balance(&deps.storage).is_zero());
+}
+
+#[test]
+fn client_jackpot_pays_only_its_own_client() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  setup(deps.as_mut(), &env, Some(GAME_1));
+
+  // only the jackpot's own client feeds it
+  process(deps.as_mut(), &env, GAME_1, PLAYER_1, 1000, 0, None).unwrap();
+  process(deps.as_mut(), &env, GAME_2, PLAYER_2, 1000, 0, None).unwrap();
+  assert_eq!(balance(&deps.storage), Uint128::from(100u128));
+
+  let err = process(
+    deps.as_mut(),
+    &env,
+    GAME_2,
+    PLAYER_2,
+    0,
+    10,
+    Some("jackpot"),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::JackpotClientMismatch));
+
+  let err = process(
+    deps.as_mut(),
+    &env,
+    GAME_1,
+    PLAYER_1,
+    0,
+    101,
+    Some("jackpot"),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::InsufficientJackpotBalance));
+
+  process(
+    deps.as_mut(),
+    &env,
+    GAME_1,
+    PLAYER_1,
+    0,
+    100,
+    Some("jackpot"),
+  )
+  .unwrap();
+  assert!(balance(&deps.storage).is_zero());
+}
+
+#[test]
+fn jackpot_job_without_win_is_rejected() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  setup(deps.as_mut(), &env, None);
+
+  let err = process(
+    deps.as_mut(),
+    &env,
+    GAME_1,
+    PLAYER_1,
+    100,
+    0,
+    Some("jackpot"),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::JackpotWithoutPayout));
+
+  let err = process(
+    deps.as_mut(),
+    &env,
+    GAME_1,
+    PLAYER_1,
+    100,
+    100,
+    Some("jackpot"),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::JackpotWithoutPayout));
+}
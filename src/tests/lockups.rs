@@ -0,0 +1,89 @@
+use cosmwasm_std::{
+  coins,
+  testing::{mock_dependencies, mock_env},
+  Addr, DepsMut, Env, Response, Uint128, Uint64,
+};
+
+use super::{advance, connect, execute, instantiate, mock_config, process, stake, DENOM};
+use crate::{
+  error::{ContractError, ContractResult},
+  models::LockupTier,
+  msg::{ExecuteMsg, PoolMsg},
+  state::{sync_account_readonly, STAKE_ACCOUNTS},
+};
+
+/// Set up a house with a single 100-second lockup tier at 2x.
+fn setup(
+  deps: DepsMut,
+  env: &Env,
+) {
+  let mut config = mock_config();
+  config.lockup_tiers = Some(vec![LockupTier {
+    seconds: Uint64::from(100u64),
+    multiplier: Uint128::from(2_000_000u128),
+  }]);
+  instantiate(deps, env, config);
+}
+
+fn stake_locked(
+  deps: DepsMut,
+  env: &Env,
+  staker: &str,
+  amount: u128,
+  lockup_seconds: u64,
+) -> ContractResult<Response> {
+  execute(
+    deps,
+    env,
+    staker,
+    &coins(amount, DENOM),
+    ExecuteMsg::Pool(PoolMsg::Stake {
+      amount: Uint128::from(amount),
+      lockup_seconds: Some(Uint64::from(lockup_seconds)),
+      tranche: None,
+    }),
+  )
+}
+
+#[test]
+fn locked_stake_cannot_unstake_before_expiry() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  setup(deps.as_mut(), &env);
+
+  let err = stake_locked(deps.as_mut(), &env, "alice", 1000, 50).unwrap_err();
+  assert!(matches!(err, ContractError::InvalidLockupTier));
+
+  stake_locked(deps.as_mut(), &env, "alice", 1000, 100).unwrap();
+
+  let unstake = ExecuteMsg::Pool(PoolMsg::Unstake);
+
+  advance(&mut env, 99);
+  let err = execute(deps.as_mut(), &env, "alice", &[], unstake.clone()).unwrap_err();
+  assert!(matches!(err, ContractError::LockupNotExpired));
+
+  advance(&mut env, 1);
+  execute(deps.as_mut(), &env, "alice", &[], unstake).unwrap();
+}
+
+#[test]
+fn locked_stake_earns_weighted_dividends() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  setup(deps.as_mut(), &env);
+
+  stake_locked(deps.as_mut(), &env, "alice", 1000, 100).unwrap();
+  stake(deps.as_mut(), &env, "bob", 1000).unwrap();
+  connect(deps.as_mut(), &env, "game");
+  process(deps.as_mut(), &env, "game", "dave", 300, 0, None).unwrap();
+
+  // alice's liquidity is weighted at 2x against bob's 1x
+  for (staker, dividends) in [("alice", 200u128), ("bob", 100u128)] {
+    let mut account = STAKE_ACCOUNTS
+      .load(&deps.storage, Addr::unchecked(staker))
+      .unwrap();
+    sync_account_readonly(&deps.storage, &deps.api, &mut account, true).unwrap();
+    assert_eq!(account.dividends, Uint128::from(dividends));
+    assert_eq!(account.liquidity, Uint128::from(1000u128));
+  }
+}
@@ -0,0 +1,228 @@
+mod governance;
+mod initiators;
+mod jackpots;
+mod lockups;
+mod pause;
+mod player_limits;
+mod rakeback;
+mod rebond;
+mod referrals;
+mod stake_limits;
+mod staked_at_height;
+mod timelock;
+mod unbonding;
+
+use cosmwasm_std::{
+  coins, from_binary, testing::mock_info, Addr, Coin, Deps, DepsMut, Env, Response, Uint128, Uint64,
+};
+use cw_lib::models::Token;
+use serde::de::DeserializeOwned;
+
+use crate::{
+  contract,
+  error::ContractResult,
+  models::{AccountTokenAmount, Config, RateLimitConfig},
+  msg::{ClientInitArgs, ClientMsg, ConfigUpdates, ExecuteMsg, InstantiateMsg, PoolMsg, QueryMsg},
+  utils::PCT_DENOMINATOR,
+};
+
+pub const OWNER: &str = "owner";
+pub const DENOM: &str = "ujuno";
+
+/// Return a config with no optional features enabled and rate limits that
+/// never throttle.
+pub fn mock_config() -> Config {
+  let rate_limit = RateLimitConfig {
+    interval_seconds: Uint64::from(60u64),
+    max_pct_change: Uint128::from(PCT_DENOMINATOR),
+  };
+  Config {
+    restake_rate: Uint128::zero(),
+    tax_rate: Uint128::zero(),
+    unbonding_seconds: Uint64::zero(),
+    account_rate_limit: rate_limit.clone(),
+    default_client_rate_limit: rate_limit,
+    timelock_seconds: None,
+    max_event_log_size: None,
+    large_payout_threshold: None,
+    stats_bucket_seconds: None,
+    stats_retention_buckets: None,
+    player_stats_enabled: None,
+    player_limits_delay_seconds: None,
+    rakeback_rate: None,
+    referral_rate: None,
+    lockup_tiers: None,
+    junior_revenue_multiplier: None,
+    unbonding_shares_losses: None,
+    unbonding_shares_gains: None,
+    max_pool_delegation: None,
+    min_stake: None,
+    max_stake: None,
+    staker_acl: None,
+    governance: None,
+  }
+}
+
+/// Return config updates that leave every field unchanged.
+pub fn config_updates() -> ConfigUpdates {
+  ConfigUpdates {
+    restake_rate: None,
+    tax_rate: None,
+    unbonding_seconds: None,
+    account_rate_limit: None,
+    default_client_rate_limit: None,
+    timelock_seconds: None,
+    max_event_log_size: None,
+    large_payout_threshold: None,
+    stats_bucket_seconds: None,
+    stats_retention_buckets: None,
+    player_stats_enabled: None,
+    player_limits_delay_seconds: None,
+    rakeback_rate: None,
+    referral_rate: None,
+    lockup_tiers: None,
+    junior_revenue_multiplier: None,
+    unbonding_shares_losses: None,
+    unbonding_shares_gains: None,
+    max_pool_delegation: None,
+    min_stake: None,
+    max_stake: None,
+    staker_acl: None,
+    governance: None,
+  }
+}
+
+/// Instantiate the house, owned by OWNER, with a native token pool.
+pub fn instantiate(
+  deps: DepsMut,
+  env: &Env,
+  config: Config,
+) {
+  contract::instantiate(
+    deps,
+    env.clone(),
+    mock_info(OWNER, &[]),
+    InstantiateMsg {
+      owner: None,
+      taxes: None,
+      token: Token::Native {
+        denom: DENOM.to_owned(),
+      },
+      config,
+    },
+  )
+  .unwrap();
+}
+
+/// Execute a message as the given sender, attaching the given funds.
+pub fn execute(
+  deps: DepsMut,
+  env: &Env,
+  sender: &str,
+  funds: &[Coin],
+  msg: ExecuteMsg,
+) -> ContractResult<Response> {
+  contract::execute(deps, env.clone(), mock_info(sender, funds), msg)
+}
+
+/// Run a query and deserialize its response.
+pub fn query<T: DeserializeOwned>(
+  deps: Deps,
+  env: &Env,
+  msg: QueryMsg,
+) -> T {
+  from_binary(&contract::query(deps, env.clone(), msg).unwrap()).unwrap()
+}
+
+/// Connect a client with no budget and the default rate limit.
+pub fn connect(
+  deps: DepsMut,
+  env: &Env,
+  client: &str,
+) {
+  execute(
+    deps,
+    env,
+    OWNER,
+    &[],
+    ExecuteMsg::Client(ClientMsg::Connect(ClientInitArgs {
+      address: Some(Addr::unchecked(client)),
+      name: None,
+      description: None,
+      url: None,
+      budget: None,
+      rate_limit: None,
+    })),
+  )
+  .unwrap();
+}
+
+/// Process a job from the given client in which the initiator pays the
+/// incoming amount and receives the outgoing amount, either of which may be
+/// zero.
+pub fn process(
+  deps: DepsMut,
+  env: &Env,
+  client: &str,
+  initiator: &str,
+  incoming: u128,
+  outgoing: u128,
+  jackpot: Option<&str>,
+) -> ContractResult<Response> {
+  let amount = |amount: u128| {
+    if amount == 0 {
+      None
+    } else {
+      Some(AccountTokenAmount {
+        address: Addr::unchecked(initiator),
+        amount: Uint128::from(amount),
+      })
+    }
+  };
+  let funds = if incoming == 0 {
+    vec![]
+  } else {
+    coins(incoming, DENOM)
+  };
+  execute(
+    deps,
+    env,
+    client,
+    &funds,
+    ExecuteMsg::Process {
+      initiator: Addr::unchecked(initiator),
+      incoming: amount(incoming),
+      outgoing: amount(outgoing),
+      jackpot: jackpot.map(|name| name.to_owned()),
+    },
+  )
+}
+
+/// Move the env forward by the given number of seconds and one block.
+pub fn advance(
+  env: &mut Env,
+  seconds: u64,
+) {
+  env.block.time = env.block.time.plus_seconds(seconds);
+  env.block.height += 1;
+}
+
+/// Stake the given amount of the pool's token as the given staker.
+pub fn stake(
+  deps: DepsMut,
+  env: &Env,
+  staker: &str,
+  amount: u128,
+) -> ContractResult<Response> {
+  execute(
+    deps,
+    env,
+    staker,
+    &coins(amount, DENOM),
+    ExecuteMsg::Pool(PoolMsg::Stake {
+      amount: Uint128::from(amount),
+      lockup_seconds: None,
+      tranche: None,
+    }),
+  )
+}
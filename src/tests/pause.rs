@@ -0,0 +1,110 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+use super::{execute, instantiate, mock_config, stake, OWNER};
+use crate::{
+  error::ContractError,
+  models::PauseScope,
+  msg::{ExecuteMsg, PoolMsg},
+  state::PAUSED_SCOPES,
+};
+
+#[test]
+fn paused_scope_blocks_only_its_own_handlers() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+  stake(deps.as_mut(), &env, "alice", 1000).unwrap();
+
+  execute(
+    deps.as_mut(),
+    &env,
+    OWNER,
+    &[],
+    ExecuteMsg::Pause {
+      scopes: vec![PauseScope::Unstaking],
+    },
+  )
+  .unwrap();
+
+  // staking stays open while exits are paused
+  stake(deps.as_mut(), &env, "alice", 100).unwrap();
+  let err = execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Pool(PoolMsg::Unstake),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::Paused));
+
+  execute(
+    deps.as_mut(),
+    &env,
+    OWNER,
+    &[],
+    ExecuteMsg::Unpause {
+      scopes: Some(vec![PauseScope::Unstaking]),
+    },
+  )
+  .unwrap();
+  execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Pool(PoolMsg::Unstake),
+  )
+  .unwrap();
+}
+
+#[test]
+fn unpause_without_scopes_clears_every_scope() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+
+  execute(
+    deps.as_mut(),
+    &env,
+    OWNER,
+    &[],
+    ExecuteMsg::Pause {
+      scopes: vec![PauseScope::Staking, PauseScope::Claims],
+    },
+  )
+  .unwrap();
+  let err = stake(deps.as_mut(), &env, "alice", 1000).unwrap_err();
+  assert!(matches!(err, ContractError::Paused));
+
+  execute(
+    deps.as_mut(),
+    &env,
+    OWNER,
+    &[],
+    ExecuteMsg::Unpause { scopes: None },
+  )
+  .unwrap();
+  assert!(PAUSED_SCOPES.load(&deps.storage).unwrap().is_empty());
+  stake(deps.as_mut(), &env, "alice", 1000).unwrap();
+}
+
+#[test]
+fn only_owner_can_pause() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+
+  let err = execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Pause {
+      scopes: vec![PauseScope::Staking],
+    },
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::NotAuthorized {}));
+  assert!(PAUSED_SCOPES.load(&deps.storage).unwrap().is_empty());
+}
@@ -0,0 +1,209 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env},
+  Addr, DepsMut, Env, Timestamp, Uint128, Uint64,
+};
+
+use super::{advance, execute, instantiate, mock_config};
+use crate::{
+  error::{ContractError, ContractResult},
+  models::{LimitPeriod, PeriodLimit, PlayerLimits},
+  msg::{ExecuteMsg, PlayerMsg},
+  state::{enforce_player_limits, load_player_limits, PENDING_PLAYER_LIMITS, PLAYER_LIMITS},
+};
+
+const PLAYER: &str = "alice";
+
+fn setup(
+  deps: DepsMut,
+  env: &Env,
+) {
+  let mut config = mock_config();
+  config.player_limits_delay_seconds = Some(Uint64::from(100u64));
+  instantiate(deps, env, config);
+}
+
+fn daily(amount: u128) -> Option<PeriodLimit> {
+  Some(PeriodLimit {
+    period: LimitPeriod::Daily,
+    amount: Uint128::from(amount),
+  })
+}
+
+fn limits(
+  excluded_until: Option<u64>,
+  loss_limit: Option<PeriodLimit>,
+  wager_limit: Option<PeriodLimit>,
+) -> PlayerLimits {
+  PlayerLimits {
+    excluded_until: excluded_until.map(Timestamp::from_seconds),
+    loss_limit,
+    wager_limit,
+  }
+}
+
+fn set_limits(
+  deps: DepsMut,
+  env: &Env,
+  limits: PlayerLimits,
+) -> ContractResult<()> {
+  execute(
+    deps,
+    env,
+    PLAYER,
+    &[],
+    ExecuteMsg::Player(PlayerMsg::SetLimits { limits }),
+  )?;
+  Ok(())
+}
+
+#[test]
+fn tightened_limits_apply_now_and_loosened_limits_wait() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  let player = Addr::unchecked(PLAYER);
+  setup(deps.as_mut(), &env);
+
+  set_limits(deps.as_mut(), &env, limits(None, daily(100), None)).unwrap();
+  assert_eq!(
+    PLAYER_LIMITS.load(&deps.storage, player.clone()).unwrap(),
+    limits(None, daily(100), None)
+  );
+  assert!(!PENDING_PLAYER_LIMITS.has(&deps.storage, player.clone()));
+
+  // a loss of 100 is allowed but any more is not
+  enforce_player_limits(
+    &mut deps.storage,
+    &env.block,
+    &player,
+    Uint128::from(100u128),
+    Uint128::zero(),
+  )
+  .unwrap();
+  let err = enforce_player_limits(
+    &mut deps.storage,
+    &env.block,
+    &player,
+    Uint128::from(1u128),
+    Uint128::zero(),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::PlayerLimitReached));
+
+  // raising the limit waits out the delay
+  set_limits(deps.as_mut(), &env, limits(None, daily(500), None)).unwrap();
+  assert_eq!(
+    PLAYER_LIMITS.load(&deps.storage, player.clone()).unwrap(),
+    limits(None, daily(100), None)
+  );
+  assert!(PENDING_PLAYER_LIMITS.has(&deps.storage, player.clone()));
+
+  advance(&mut env, 99);
+  assert_eq!(
+    load_player_limits(&mut deps.storage, env.block.time, &player).unwrap(),
+    Some(limits(None, daily(100), None))
+  );
+
+  advance(&mut env, 1);
+  assert_eq!(
+    load_player_limits(&mut deps.storage, env.block.time, &player).unwrap(),
+    Some(limits(None, daily(500), None))
+  );
+  assert!(!PENDING_PLAYER_LIMITS.has(&deps.storage, player));
+}
+
+#[test]
+fn unexpired_exclusion_is_never_shortened() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  let player = Addr::unchecked(PLAYER);
+  setup(deps.as_mut(), &env);
+
+  let excluded_until = env.block.time.seconds() + 1000;
+  set_limits(
+    deps.as_mut(),
+    &env,
+    limits(Some(excluded_until), None, None),
+  )
+  .unwrap();
+
+  // lifting the exclusion neither takes effect nor gets queued
+  set_limits(deps.as_mut(), &env, limits(None, None, None)).unwrap();
+  assert!(!PENDING_PLAYER_LIMITS.has(&deps.storage, player.clone()));
+
+  advance(&mut env, 500);
+  assert_eq!(
+    load_player_limits(&mut deps.storage, env.block.time, &player).unwrap(),
+    Some(limits(Some(excluded_until), None, None))
+  );
+  let err = enforce_player_limits(
+    &mut deps.storage,
+    &env.block,
+    &player,
+    Uint128::from(1u128),
+    Uint128::zero(),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::PlayerLimitReached));
+
+  // neither can a pending loosening of another limit shorten it
+  set_limits(
+    deps.as_mut(),
+    &env,
+    limits(Some(excluded_until), daily(100), None),
+  )
+  .unwrap();
+  set_limits(deps.as_mut(), &env, limits(None, daily(500), None)).unwrap();
+  advance(&mut env, 100);
+  assert_eq!(
+    load_player_limits(&mut deps.storage, env.block.time, &player).unwrap(),
+    Some(limits(Some(excluded_until), daily(500), None))
+  );
+
+  // the exclusion can still be extended
+  set_limits(
+    deps.as_mut(),
+    &env,
+    limits(Some(excluded_until + 1000), daily(500), None),
+  )
+  .unwrap();
+  assert_eq!(
+    PLAYER_LIMITS.load(&deps.storage, player).unwrap(),
+    limits(Some(excluded_until + 1000), daily(500), None)
+  );
+}
+
+#[test]
+fn promotion_only_loosens_loosened_limits() {
+  let env = mock_env();
+  let current = limits(None, daily(100), daily(200));
+  let pending = limits(None, daily(500), daily(150));
+
+  assert_eq!(
+    current.loosen(&pending, env.block.time),
+    limits(None, daily(500), daily(200))
+  );
+}
+
+#[test]
+fn cancelling_requires_pending_limits() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  let player = Addr::unchecked(PLAYER);
+  setup(deps.as_mut(), &env);
+
+  let cancel = ExecuteMsg::Player(PlayerMsg::CancelPendingLimits);
+
+  let err = execute(deps.as_mut(), &env, PLAYER, &[], cancel.clone()).unwrap_err();
+  assert!(matches!(err, ContractError::NoPendingLimits));
+
+  set_limits(deps.as_mut(), &env, limits(None, daily(100), None)).unwrap();
+  set_limits(deps.as_mut(), &env, limits(None, None, None)).unwrap();
+  assert!(PENDING_PLAYER_LIMITS.has(&deps.storage, player.clone()));
+
+  execute(deps.as_mut(), &env, PLAYER, &[], cancel).unwrap();
+  assert!(!PENDING_PLAYER_LIMITS.has(&deps.storage, player.clone()));
+  assert_eq!(
+    PLAYER_LIMITS.load(&deps.storage, player).unwrap(),
+    limits(None, daily(100), None)
+  );
+}
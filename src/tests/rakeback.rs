@@ -0,0 +1,78 @@
+use cosmwasm_std::{
+  coins,
+  testing::{mock_dependencies, mock_env},
+  Addr, BankMsg, CosmosMsg, DepsMut, Env, Uint128,
+};
+
+use super::{connect, execute, instantiate, mock_config, process, stake, DENOM};
+use crate::{
+  error::ContractError,
+  msg::{ExecuteMsg, PlayerMsg},
+  state::{POOL, RAKEBACK, RAKEBACK_TOTAL},
+};
+
+const CLIENT: &str = "game";
+const PLAYER: &str = "bob";
+const STAKER: &str = "carol";
+
+/// Set up a house with a 10% tax and 5% rakeback, and have the player lose
+/// 1000 to it.
+fn setup(
+  mut deps: DepsMut,
+  env: &Env,
+) {
+  let mut config = mock_config();
+  config.tax_rate = Uint128::from(100_000u128);
+  config.rakeback_rate = Some(Uint128::from(50_000u128));
+  instantiate(deps.branch(), env, config);
+  stake(deps.branch(), env, STAKER, 10_000).unwrap();
+  connect(deps.branch(), env, CLIENT);
+  process(deps, env, CLIENT, PLAYER, 1000, 0, None).unwrap();
+}
+
+#[test]
+fn rakeback_is_taken_out_of_revenue_after_tax() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  setup(deps.as_mut(), &env);
+
+  assert_eq!(
+    RAKEBACK
+      .load(&deps.storage, Addr::unchecked(PLAYER))
+      .unwrap(),
+    Uint128::from(50u128)
+  );
+  assert_eq!(
+    RAKEBACK_TOTAL.load(&deps.storage).unwrap(),
+    Uint128::from(50u128)
+  );
+
+  let pool = POOL.load(&deps.storage).unwrap();
+  assert_eq!(pool.taxes, Uint128::from(100u128));
+  assert_eq!(pool.dividends, Uint128::from(850u128));
+}
+
+#[test]
+fn claiming_rakeback_sends_and_clears_it() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  setup(deps.as_mut(), &env);
+
+  let claim = ExecuteMsg::Player(PlayerMsg::ClaimRakeback {
+    to_bank_account: None,
+  });
+
+  let resp = execute(deps.as_mut(), &env, PLAYER, &[], claim.clone()).unwrap();
+  assert_eq!(
+    resp.messages[0].msg,
+    CosmosMsg::Bank(BankMsg::Send {
+      to_address: PLAYER.to_owned(),
+      amount: coins(50, DENOM),
+    })
+  );
+  assert!(!RAKEBACK.has(&deps.storage, Addr::unchecked(PLAYER)));
+  assert!(RAKEBACK_TOTAL.load(&deps.storage).unwrap().is_zero());
+
+  let err = execute(deps.as_mut(), &env, PLAYER, &[], claim).unwrap_err();
+  assert!(matches!(err, ContractError::NothingToClaim));
+}
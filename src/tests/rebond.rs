@@ -0,0 +1,78 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env},
+  Addr, Uint128,
+};
+
+use super::{connect, execute, instantiate, mock_config, process, stake};
+use crate::{
+  error::ContractError,
+  msg::{ExecuteMsg, PoolMsg},
+  state::{sync_account_readonly, LEDGER, N_STAKE_ACCOUNTS_UNBONDING, POOL, STAKE_ACCOUNTS},
+};
+
+#[test]
+fn rebond_restores_stake_and_resumes_syncing() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+  stake(deps.as_mut(), &env, "alice", 1000).unwrap();
+  stake(deps.as_mut(), &env, "bob", 1000).unwrap();
+  connect(deps.as_mut(), &env, "game");
+
+  let err = execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Pool(PoolMsg::Rebond),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::NotUnstaked));
+
+  execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Pool(PoolMsg::Unstake),
+  )
+  .unwrap();
+  assert_eq!(N_STAKE_ACCOUNTS_UNBONDING.load(&deps.storage).unwrap(), 1);
+
+  // only bob shares revenue earned while alice is unbonding
+  process(deps.as_mut(), &env, "game", "dave", 100, 0, None).unwrap();
+  assert_eq!(LEDGER.load(&deps.storage, 0).unwrap().ref_count, 1);
+
+  execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Pool(PoolMsg::Rebond),
+  )
+  .unwrap();
+  assert_eq!(N_STAKE_ACCOUNTS_UNBONDING.load(&deps.storage).unwrap(), 0);
+
+  let account = STAKE_ACCOUNTS
+    .load(&deps.storage, Addr::unchecked("alice"))
+    .unwrap();
+  assert!(account.unbonding.is_none());
+  assert_eq!(account.delegation, Uint128::from(1000u128));
+  assert_eq!(account.liquidity, Uint128::from(1000u128));
+  assert_eq!(
+    POOL.load(&deps.storage).unwrap().delegation,
+    Uint128::from(2000u128)
+  );
+
+  // both share revenue earned after alice rebonds
+  process(deps.as_mut(), &env, "game", "dave", 300, 0, None).unwrap();
+  assert_eq!(LEDGER.load(&deps.storage, 1).unwrap().ref_count, 2);
+
+  for (staker, dividends) in [("alice", 150u128), ("bob", 250u128)] {
+    let mut account = STAKE_ACCOUNTS
+      .load(&deps.storage, Addr::unchecked(staker))
+      .unwrap();
+    sync_account_readonly(&deps.storage, &deps.api, &mut account, true).unwrap();
+    assert_eq!(account.dividends, Uint128::from(dividends));
+  }
+}
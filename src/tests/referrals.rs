@@ -0,0 +1,105 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env},
+  Addr, DepsMut, Env, Uint128,
+};
+
+use super::{connect, execute, instantiate, mock_config, process, stake, OWNER};
+use crate::{
+  error::{ContractError, ContractResult},
+  msg::{ExecuteMsg, ReferralMsg},
+  state::{POOL, REFERRAL_ACCOUNTS, REFERRERS},
+};
+
+const CLIENT: &str = "game";
+const PLAYER: &str = "bob";
+const STAKER: &str = "carol";
+
+fn set_referrer(
+  deps: DepsMut,
+  env: &Env,
+  player: &str,
+  referrer: &str,
+) -> ContractResult<()> {
+  execute(
+    deps,
+    env,
+    player,
+    &[],
+    ExecuteMsg::Referrals(ReferralMsg::SetReferrer {
+      referrer: Addr::unchecked(referrer),
+    }),
+  )?;
+  Ok(())
+}
+
+#[test]
+fn referrer_must_be_approved_and_set_once() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+
+  let err = set_referrer(deps.as_mut(), &env, PLAYER, "dave").unwrap_err();
+  assert!(matches!(err, ContractError::ReferrerNotApproved));
+
+  let err = set_referrer(deps.as_mut(), &env, OWNER, OWNER).unwrap_err();
+  assert!(matches!(err, ContractError::InvalidReferrer));
+
+  // the owner is always an approved referrer
+  set_referrer(deps.as_mut(), &env, PLAYER, OWNER).unwrap();
+  assert_eq!(
+    REFERRERS
+      .load(&deps.storage, Addr::unchecked(PLAYER))
+      .unwrap(),
+    Addr::unchecked(OWNER)
+  );
+  assert_eq!(
+    REFERRAL_ACCOUNTS
+      .load(&deps.storage, Addr::unchecked(OWNER))
+      .unwrap()
+      .n_referrals,
+    1
+  );
+
+  let err = set_referrer(deps.as_mut(), &env, PLAYER, OWNER).unwrap_err();
+  assert!(matches!(err, ContractError::ReferrerAlreadySet));
+}
+
+#[test]
+fn referral_share_is_split_off_before_tax() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  let mut config = mock_config();
+  config.tax_rate = Uint128::from(100_000u128);
+  config.referral_rate = Some(Uint128::from(100_000u128));
+  instantiate(deps.as_mut(), &env, config);
+  stake(deps.as_mut(), &env, STAKER, 10_000).unwrap();
+  connect(deps.as_mut(), &env, CLIENT);
+  set_referrer(deps.as_mut(), &env, PLAYER, OWNER).unwrap();
+
+  process(deps.as_mut(), &env, CLIENT, PLAYER, 1000, 0, None).unwrap();
+
+  let account = REFERRAL_ACCOUNTS
+    .load(&deps.storage, Addr::unchecked(OWNER))
+    .unwrap();
+  assert_eq!(account.earned, Uint128::from(100u128));
+  assert_eq!(account.balance, Uint128::from(100u128));
+
+  let pool = POOL.load(&deps.storage).unwrap();
+  assert_eq!(pool.taxes, Uint128::from(90u128));
+  assert_eq!(pool.dividends, Uint128::from(810u128));
+
+  // claiming moves the balance into the claimed total
+  execute(
+    deps.as_mut(),
+    &env,
+    OWNER,
+    &[],
+    ExecuteMsg::Referrals(ReferralMsg::Claim),
+  )
+  .unwrap();
+  let account = REFERRAL_ACCOUNTS
+    .load(&deps.storage, Addr::unchecked(OWNER))
+    .unwrap();
+  assert!(account.balance.is_zero());
+  assert_eq!(account.claimed, Uint128::from(100u128));
+}
@@ -0,0 +1,69 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env},
+  to_binary, Addr, SystemResult, Uint128, WasmQuery,
+};
+
+use super::{config_updates, execute, instantiate, mock_config, stake, OWNER};
+use crate::{
+  error::ContractError,
+  msg::{ExecuteMsg, OptionalUpdate},
+};
+
+#[test]
+fn stake_limits_bound_each_account_and_the_pool() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  let mut config = mock_config();
+  config.min_stake = Some(Uint128::from(100u128));
+  config.max_stake = Some(Uint128::from(1000u128));
+  config.max_pool_delegation = Some(Uint128::from(1500u128));
+  instantiate(deps.as_mut(), &env, config);
+
+  let err = stake(deps.as_mut(), &env, "alice", 50).unwrap_err();
+  assert!(matches!(err, ContractError::StakeBelowMinimum));
+
+  let err = stake(deps.as_mut(), &env, "alice", 1001).unwrap_err();
+  assert!(matches!(err, ContractError::StakeAboveMaximum));
+
+  // the maximum applies to the account's total delegation
+  stake(deps.as_mut(), &env, "alice", 1000).unwrap();
+  let err = stake(deps.as_mut(), &env, "alice", 1).unwrap_err();
+  assert!(matches!(err, ContractError::StakeAboveMaximum));
+
+  let err = stake(deps.as_mut(), &env, "bob", 600).unwrap_err();
+  assert!(matches!(err, ContractError::PoolCapacityExceeded));
+  stake(deps.as_mut(), &env, "bob", 500).unwrap();
+}
+
+#[test]
+fn staker_acl_gates_staking_until_cleared() {
+  let mut deps = mock_dependencies();
+  let env = mock_env();
+  let mut config = mock_config();
+  config.staker_acl = Some(Addr::unchecked("stakers"));
+  instantiate(deps.as_mut(), &env, config);
+
+  // the ACL only allows alice
+  deps.querier.update_wasm(|query| match query {
+    WasmQuery::Smart { msg, .. } => SystemResult::Ok(cosmwasm_std::ContractResult::Ok(
+      to_binary(&String::from_utf8_lossy(msg.as_slice()).contains("alice")).unwrap(),
+    )),
+    _ => panic!("unexpected query"),
+  });
+
+  stake(deps.as_mut(), &env, "alice", 1000).unwrap();
+  let err = stake(deps.as_mut(), &env, "bob", 1000).unwrap_err();
+  assert!(matches!(err, ContractError::StakerNotAllowed));
+
+  let mut updates = config_updates();
+  updates.staker_acl = Some(OptionalUpdate::Clear);
+  execute(
+    deps.as_mut(),
+    &env,
+    OWNER,
+    &[],
+    ExecuteMsg::SetConfig { config: updates },
+  )
+  .unwrap();
+  stake(deps.as_mut(), &env, "bob", 1000).unwrap();
+}
@@ -0,0 +1,120 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env},
+  Addr, Deps, Env, Uint128, Uint64,
+};
+
+use super::{advance, instantiate, mock_config, query, stake};
+use crate::msg::{
+  QueryMsg, StakeAtResponse, StakedBalanceAtHeightResponse, TotalStakeAtResponse,
+  TotalStakedAtHeightResponse,
+};
+
+fn balance_at(
+  deps: Deps,
+  env: &Env,
+  height: u64,
+) -> Uint128 {
+  let resp: StakedBalanceAtHeightResponse = query(
+    deps,
+    env,
+    QueryMsg::StakedBalanceAtHeight {
+      address: Addr::unchecked("alice"),
+      height: Some(height),
+    },
+  );
+  resp.balance
+}
+
+fn total_at(
+  deps: Deps,
+  env: &Env,
+  height: u64,
+) -> Uint128 {
+  let resp: TotalStakedAtHeightResponse = query(
+    deps,
+    env,
+    QueryMsg::TotalStakedAtHeight {
+      height: Some(height),
+    },
+  );
+  resp.total
+}
+
+#[test]
+fn stake_is_visible_from_the_next_block() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+
+  let h0 = env.block.height;
+  stake(deps.as_mut(), &env, "alice", 100).unwrap();
+  stake(deps.as_mut(), &env, "bob", 200).unwrap();
+  assert_eq!(balance_at(deps.as_ref(), &env, h0), Uint128::zero());
+  assert_eq!(total_at(deps.as_ref(), &env, h0), Uint128::zero());
+
+  advance(&mut env, 1);
+  let h1 = env.block.height;
+  assert_eq!(balance_at(deps.as_ref(), &env, h1), Uint128::from(100u128));
+  assert_eq!(total_at(deps.as_ref(), &env, h1), Uint128::from(300u128));
+
+  // a second stake doesn't rewrite the current block's snapshot
+  stake(deps.as_mut(), &env, "alice", 50).unwrap();
+  assert_eq!(balance_at(deps.as_ref(), &env, h1), Uint128::from(100u128));
+
+  advance(&mut env, 1);
+  let h2 = env.block.height;
+  assert_eq!(balance_at(deps.as_ref(), &env, h2), Uint128::from(150u128));
+  assert_eq!(total_at(deps.as_ref(), &env, h2), Uint128::from(350u128));
+  assert_eq!(balance_at(deps.as_ref(), &env, h0), Uint128::zero());
+
+  // the height defaults to the current block
+  let resp: StakedBalanceAtHeightResponse = query(
+    deps.as_ref(),
+    &env,
+    QueryMsg::StakedBalanceAtHeight {
+      address: Addr::unchecked("alice"),
+      height: None,
+    },
+  );
+  assert_eq!(resp.height, h2);
+  assert_eq!(resp.balance, Uint128::from(150u128));
+}
+
+#[test]
+fn stake_at_reports_delegation_and_liquidity() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  instantiate(deps.as_mut(), &env, mock_config());
+
+  let h0 = env.block.height;
+  stake(deps.as_mut(), &env, "alice", 100).unwrap();
+  advance(&mut env, 1);
+
+  let resp: StakeAtResponse = query(
+    deps.as_ref(),
+    &env,
+    QueryMsg::StakeAt {
+      address: Addr::unchecked("alice"),
+      height: Some(Uint64::from(h0)),
+    },
+  );
+  assert_eq!(resp.balance, Uint128::zero());
+  assert_eq!(resp.liquidity, Uint128::zero());
+
+  let resp: StakeAtResponse = query(
+    deps.as_ref(),
+    &env,
+    QueryMsg::StakeAt {
+      address: Addr::unchecked("alice"),
+      height: None,
+    },
+  );
+  assert_eq!(resp.height, Uint64::from(env.block.height));
+  assert_eq!(resp.balance, Uint128::from(100u128));
+  assert_eq!(resp.liquidity, Uint128::from(100u128));
+
+  let resp: TotalStakeAtResponse =
+    query(deps.as_ref(), &env, QueryMsg::TotalStakeAt { height: None });
+  assert_eq!(resp.total, Uint128::from(100u128));
+  assert_eq!(resp.liquidity, Uint128::from(100u128));
+}
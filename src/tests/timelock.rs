@@ -0,0 +1,91 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env},
+  DepsMut, Env, Uint128, Uint64,
+};
+
+use super::{advance, config_updates, execute, instantiate, mock_config, OWNER};
+use crate::{
+  error::ContractError,
+  msg::{ExecuteMsg, TimelockMsg},
+  state::{CONFIG, PENDING_CHANGES},
+};
+
+fn setup(
+  deps: DepsMut,
+  env: &Env,
+) {
+  let mut config = mock_config();
+  config.timelock_seconds = Some(Uint64::from(100u64));
+  instantiate(deps, env, config);
+}
+
+fn queue_tax_rate(
+  deps: DepsMut,
+  env: &Env,
+) {
+  let mut updates = config_updates();
+  updates.tax_rate = Some(Uint128::from(1000u128));
+  execute(
+    deps,
+    env,
+    OWNER,
+    &[],
+    ExecuteMsg::SetConfig { config: updates },
+  )
+  .unwrap();
+}
+
+#[test]
+fn queued_change_applies_only_once_ready() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  setup(deps.as_mut(), &env);
+  queue_tax_rate(deps.as_mut(), &env);
+
+  // the change is queued rather than applied
+  assert!(CONFIG.load(&deps.storage).unwrap().tax_rate.is_zero());
+  assert!(PENDING_CHANGES.has(&deps.storage, 1));
+
+  let msg = ExecuteMsg::Timelock(TimelockMsg::Execute { id: Uint64::one() });
+
+  advance(&mut env, 99);
+  let err = execute(deps.as_mut(), &env, "anyone", &[], msg.clone()).unwrap_err();
+  assert!(matches!(err, ContractError::TimelockNotExpired));
+
+  // anyone may execute the change once its delay has elapsed
+  advance(&mut env, 1);
+  execute(deps.as_mut(), &env, "anyone", &[], msg).unwrap();
+  assert_eq!(
+    CONFIG.load(&deps.storage).unwrap().tax_rate,
+    Uint128::from(1000u128)
+  );
+  assert!(!PENDING_CHANGES.has(&deps.storage, 1));
+}
+
+#[test]
+fn cancelled_change_is_never_applied() {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  setup(deps.as_mut(), &env);
+  queue_tax_rate(deps.as_mut(), &env);
+
+  let cancel = ExecuteMsg::Timelock(TimelockMsg::Cancel { id: Uint64::one() });
+
+  let err = execute(deps.as_mut(), &env, "anyone", &[], cancel.clone()).unwrap_err();
+  assert!(matches!(err, ContractError::NotAuthorized {}));
+
+  execute(deps.as_mut(), &env, OWNER, &[], cancel).unwrap();
+  assert!(!PENDING_CHANGES.has(&deps.storage, 1));
+
+  advance(&mut env, 100);
+  let err = execute(
+    deps.as_mut(),
+    &env,
+    "anyone",
+    &[],
+    ExecuteMsg::Timelock(TimelockMsg::Execute { id: Uint64::one() }),
+  )
+  .unwrap_err();
+  assert!(matches!(err, ContractError::PendingChangeNotFound));
+  assert!(CONFIG.load(&deps.storage).unwrap().tax_rate.is_zero());
+}
@@ -0,0 +1,100 @@
+use cosmwasm_std::{
+  testing::{mock_dependencies, mock_env},
+  Addr, Uint128, Uint64,
+};
+
+use super::{advance, connect, execute, instantiate, mock_config, process, stake};
+use crate::{
+  models::Tranche,
+  msg::{ExecuteMsg, PoolMsg},
+  state::{sync_account_readonly, N_STAKE_ACCOUNTS_UNBONDING_AT_RISK, POOL, STAKE_ACCOUNTS},
+};
+
+/// Have alice unstake from a house where she and bob each staked 1000, then
+/// have the house pay out 200, and return what alice withdraws and what's left
+/// of bob's liquidity.
+fn unstake_before_loss(unbonding_shares_losses: bool) -> (String, Uint128) {
+  let mut deps = mock_dependencies();
+  let mut env = mock_env();
+  let mut config = mock_config();
+  config.unbonding_seconds = Uint64::from(100u64);
+  config.unbonding_shares_losses = Some(unbonding_shares_losses);
+  instantiate(deps.as_mut(), &env, config);
+  stake(deps.as_mut(), &env, "alice", 1000).unwrap();
+  stake(deps.as_mut(), &env, "bob", 1000).unwrap();
+  connect(deps.as_mut(), &env, "game");
+
+  execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Pool(PoolMsg::Unstake),
+  )
+  .unwrap();
+
+  let pool = POOL.load(&deps.storage).unwrap();
+  if unbonding_shares_losses {
+    assert_eq!(
+      N_STAKE_ACCOUNTS_UNBONDING_AT_RISK
+        .load(&deps.storage)
+        .unwrap(),
+      1
+    );
+    assert_eq!(
+      pool.unbonding_totals(&Tranche::Senior).liquidity,
+      Uint128::from(1000u128)
+    );
+    assert_eq!(pool.liquidity, Uint128::from(2000u128));
+  } else {
+    assert_eq!(pool.liquidity, Uint128::from(1000u128));
+  }
+
+  process(deps.as_mut(), &env, "game", "dave", 0, 200, None).unwrap();
+
+  advance(&mut env, 101);
+  let resp = execute(
+    deps.as_mut(),
+    &env,
+    "alice",
+    &[],
+    ExecuteMsg::Pool(PoolMsg::Withdraw),
+  )
+  .unwrap();
+  let withdrawn = resp
+    .attributes
+    .iter()
+    .find(|a| a.key == "amount")
+    .unwrap()
+    .value
+    .clone();
+
+  let mut bob = STAKE_ACCOUNTS
+    .load(&deps.storage, Addr::unchecked("bob"))
+    .unwrap();
+  sync_account_readonly(&deps.storage, &deps.api, &mut bob, true).unwrap();
+
+  if unbonding_shares_losses {
+    assert_eq!(
+      N_STAKE_ACCOUNTS_UNBONDING_AT_RISK
+        .load(&deps.storage)
+        .unwrap(),
+      0
+    );
+  }
+  (withdrawn, bob.liquidity)
+}
+
+#[test]
+fn unbonding_liquidity_at_risk_shares_losses() {
+  let (withdrawn, bob_liquidity) = unstake_before_loss(true);
+  assert_eq!(withdrawn, "900");
+  assert_eq!(bob_liquidity, Uint128::from(900u128));
+}
+
+#[test]
+fn unbonding_liquidity_escapes_losses_unless_configured() {
+  let (withdrawn, bob_liquidity) = unstake_before_loss(false);
+  assert_eq!(withdrawn, "1000");
+  assert_eq!(bob_liquidity, Uint128::from(800u128));
+}
@@ -1,9 +1,13 @@
-use cosmwasm_std::{Addr, Api, Storage, Uint128};
+use cosmwasm_std::{attr, Addr, Api, Attribute, Storage, Uint128};
 use cw_storage_plus::Item;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::error::{ContractError, ContractResult};
 
+/// Denominator of all percentages, so 1_000_000 means 100%.
+pub const PCT_DENOMINATOR: u128 = 1_000_000;
+pub const MAX_LOCKUP_MULTIPLIER: u128 = 10 * PCT_DENOMINATOR;
+
 pub fn increment<T>(
   storage: &mut dyn Storage,
   item: &Item<T>,
@@ -30,7 +34,7 @@ pub fn mul_pct(
   total: Uint128,
   pct: Uint128,
 ) -> Uint128 {
-  total.multiply_ratio(pct, Uint128::from(1_000_000u128))
+  total.multiply_ratio(pct, Uint128::from(PCT_DENOMINATOR))
 }
 
 pub fn require_valid_address(
@@ -41,3 +45,21 @@ pub fn require_valid_address(
     .addr_validate(addr.as_str())
     .map_err(|_| ContractError::InvalidAddress)
 }
+
+/// Build a pair of attributes recording the value of a field before and after
+/// it was changed.
+pub fn change_attrs(
+  key: &str,
+  before: String,
+  after: String,
+) -> Vec<Attribute> {
+  vec![
+    attr(format!("{}_before", key), before),
+    attr(format!("{}_after", key), after),
+  ]
+}
+
+/// Stringify an optional value, using an empty string for None.
+pub fn opt_to_string<T: ToString>(value: &Option<T>) -> String {
+  value.as_ref().map(|x| x.to_string()).unwrap_or_default()
+}